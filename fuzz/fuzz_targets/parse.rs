@@ -0,0 +1,23 @@
+#![no_main]
+
+use hex_str::{HexParser, HexString, HexStringN};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let v = HexString::try_parse(data);
+    let _ = HexString::try_parse_lower(data);
+    let _ = HexString::try_parse_upper(data);
+    let _ = HexString::try_parse_prefixed(data);
+    let _ = HexString::try_parse_separated(data, b':');
+    let _ = HexStringN::<16>::try_parse(data);
+    let _ = HexStringN::<16>::try_parse_separated(data, b':');
+
+    // feeding in two halves has to agree with parsing the whole input
+    let (a, b) = data.split_at(data.len() / 2);
+    let mut parser = HexParser::new();
+    let parsed = parser.feed(a).and_then(|()| parser.feed(b));
+    match v {
+        Ok(v) => assert_eq!(parsed.and_then(|()| parser.finish()), Ok(v)),
+        Err(_) => assert!(parsed.and_then(|()| parser.finish()).is_err()),
+    }
+});
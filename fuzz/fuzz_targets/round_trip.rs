@@ -0,0 +1,18 @@
+#![no_main]
+
+use hex_str::{HexString, HexStringN};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: (HexString, HexStringN<16>)| {
+    let (v, n) = input;
+
+    assert_eq!(HexString::try_parse(v.to_lower()), Ok(v.clone()));
+    assert_eq!(HexString::try_parse(v.to_upper()), Ok(v.clone()));
+    assert_eq!(
+        HexString::try_parse_separated(v.format_separated(':', 1), b':'),
+        Ok(v)
+    );
+
+    assert_eq!(HexStringN::<16>::try_parse(n.to_lower()), Ok(n.clone()));
+    assert_eq!(HexStringN::<16>::try_parse(n.to_upper()), Ok(n));
+});
@@ -0,0 +1,202 @@
+#![warn(clippy::pedantic)]
+#![deny(rust_2018_idioms, unused, future_incompatible, nonstandard_style)]
+
+//! Procedural macros for [`hex_str`](https://docs.rs/hex_str)
+//!
+//! Use them through `hex_str`'s `macros` feature, not directly.
+
+use proc_macro::{Delimiter, Group, Span, TokenStream, TokenTree};
+
+/// Expand a hex literal to a `HexStringN` at compile time
+///
+/// See `hex_str::hex`. The input is `$crate, literal`, the path to `hex_str`
+/// is forwarded by its `hex!` wrapper so that renamed dependencies work.
+#[proc_macro]
+pub fn hex(input: TokenStream) -> TokenStream {
+    match expand(input) {
+        Ok(v) => v,
+        Err((span, msg)) => compile_error(span, &msg),
+    }
+}
+
+fn expand(input: TokenStream) -> Result<TokenStream, (Span, String)> {
+    let mut iter = input.into_iter();
+    let krate = match (iter.next(), iter.next()) {
+        (Some(krate @ TokenTree::Ident(_)), Some(TokenTree::Punct(comma)))
+            if comma.as_char() == ',' =>
+        {
+            krate
+        }
+        _ => {
+            return Err((
+                Span::call_site(),
+                "use `hex_str::hex!` instead of invoking this macro directly".to_owned(),
+            ))
+        }
+    };
+
+    let literal = match single_token(iter.collect()) {
+        Some(TokenTree::Literal(v)) => v,
+        Some(tt) => return Err((tt.span(), "expected a string literal".to_owned())),
+        None => {
+            return Err((
+                Span::call_site(),
+                "expected a single string literal".to_owned(),
+            ))
+        }
+    };
+
+    let decoded = decode(&literal.to_string()).map_err(|msg| (literal.span(), msg))?;
+
+    let bytes: Vec<_> = decoded.iter().map(|v| format!("{v:#04x}u8")).collect();
+    let path: TokenStream = format!(
+        "::HexStringN::<{}>::new([{}])",
+        decoded.len(),
+        bytes.join(", ")
+    )
+    .parse()
+    .expect("generated code is valid");
+
+    Ok(core::iter::once(krate).chain(path).collect())
+}
+
+/// Decode `repr`, the source representation of a string or byte string
+/// literal, raw or not
+fn decode(repr: &str) -> Result<Vec<u8>, String> {
+    let contents = string_contents(repr).ok_or_else(|| "expected a string literal".to_owned())?;
+
+    let mut nibbles = Vec::with_capacity(contents.len());
+    for (i, c) in contents.char_indices() {
+        let nibble = u8::try_from(c)
+            .ok()
+            .and_then(parse_nibble)
+            .ok_or_else(|| format!("invalid character `{c}` encountered at index {i}"))?;
+
+        nibbles.push(nibble);
+    }
+
+    if nibbles.len() % 2 != 0 {
+        return Err(format!(
+            "hex literal must have an even length, encountered: `{}`",
+            nibbles.len()
+        ));
+    }
+
+    Ok(nibbles
+        .chunks_exact(2)
+        .map(|v| (v[0] << 4) | v[1])
+        .collect())
+}
+
+/// Strip the quotes, and the `b`, `r` and `#` prefixes and suffixes from
+/// `repr`, return `None` if it isn't a string or byte string literal
+fn string_contents(repr: &str) -> Option<&str> {
+    let repr = repr.strip_prefix('b').unwrap_or(repr);
+    let (repr, hashes) = match repr.strip_prefix('r') {
+        Some(v) => {
+            let trimmed = v.trim_start_matches('#');
+            (trimmed, v.len() - trimmed.len())
+        }
+        None => (repr, 0),
+    };
+
+    repr.strip_prefix('"')?
+        .strip_suffix(&"#".repeat(hashes))?
+        .strip_suffix('"')
+}
+
+/// Return the only token of `input`, looking through invisible groups that
+/// `macro_rules!` wraps forwarded fragments in
+fn single_token(input: TokenStream) -> Option<TokenTree> {
+    let mut iter = input.into_iter();
+    match (iter.next(), iter.next()) {
+        (Some(TokenTree::Group(group)), None) if group.delimiter() == Delimiter::None => {
+            single_token(group.stream())
+        }
+        (Some(tt), None) => Some(tt),
+        _ => None,
+    }
+}
+
+fn parse_nibble(v: u8) -> Option<u8> {
+    match v {
+        b'0'..=b'9' => Some(v - b'0'),
+        b'a'..=b'f' => Some(v - b'a' + 10),
+        b'A'..=b'F' => Some(v - b'A' + 10),
+        _ => None,
+    }
+}
+
+fn compile_error(span: Span, msg: &str) -> TokenStream {
+    let tokens: TokenStream = format!("::core::compile_error!({msg:?})")
+        .parse()
+        .expect("generated code is valid");
+
+    tokens.into_iter().map(|tt| respan(tt, span)).collect()
+}
+
+fn respan(mut tt: TokenTree, span: Span) -> TokenTree {
+    if let TokenTree::Group(group) = &tt {
+        let stream = group.stream().into_iter().map(|tt| respan(tt, span));
+        tt = TokenTree::Group(Group::new(group.delimiter(), stream.collect()));
+    }
+
+    tt.set_span(span);
+    tt
+}
+
+#[cfg(test)]
+mod tests {
+    use super::decode;
+
+    #[test]
+    fn decode_valid() {
+        assert_eq!(decode(r#""1a2b""#), Ok(vec![0x1a, 0x2b]));
+        assert_eq!(decode(r#""1A2b""#), Ok(vec![0x1a, 0x2b]));
+        assert_eq!(decode(r#"b"1a2B""#), Ok(vec![0x1a, 0x2b]));
+        assert_eq!(decode(r#"r"1a2b""#), Ok(vec![0x1a, 0x2b]));
+        assert_eq!(decode(r#"br"1a2b""#), Ok(vec![0x1a, 0x2b]));
+        assert_eq!(decode(r##"r#"1a2b"#"##), Ok(vec![0x1a, 0x2b]));
+        assert_eq!(decode(r###"br##"1a2b"##"###), Ok(vec![0x1a, 0x2b]));
+        assert_eq!(decode(r#""""#), Ok(vec![]));
+        assert_eq!(
+            decode(r#""d41d8cd98f00b204e9800998ecf8427e""#).map(|v| v.len()),
+            Ok(16)
+        );
+    }
+
+    #[test]
+    fn decode_invalid() {
+        assert_eq!(
+            decode(r#""1a2""#),
+            Err("hex literal must have an even length, encountered: `3`".to_owned())
+        );
+        assert_eq!(
+            decode(r#""1g""#),
+            Err("invalid character `g` encountered at index 1".to_owned())
+        );
+        assert_eq!(
+            decode(r#"r"1a 2b""#),
+            Err("invalid character ` ` encountered at index 2".to_owned())
+        );
+        assert_eq!(
+            decode(r#""1aé""#),
+            Err("invalid character `é` encountered at index 2".to_owned())
+        );
+        assert_eq!(
+            decode(r#""\x31a""#),
+            Err("invalid character `\\` encountered at index 0".to_owned())
+        );
+
+        for repr in [
+            "12",
+            "'a'",
+            "b'a'",
+            r#"c"1a""#,
+            r#"r#"1a""#,
+            r#""1a"suffix"#,
+        ] {
+            assert_eq!(decode(repr), Err("expected a string literal".to_owned()));
+        }
+    }
+}
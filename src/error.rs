@@ -1,23 +1,132 @@
 /// An error that may occur when parsing hex strings
 #[allow(clippy::module_name_repetitions)]
 #[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[non_exhaustive]
 pub enum HexStringNError {
     /// The input didn't have required length
     #[error("invalid input length, expected `{expected}`, encountered: `{encountered}`")]
     InvalidLength { expected: usize, encountered: usize },
     /// The input contained invalid character
-    #[error("invalid byte `{a:02x}{b:02x}` encountered at index {index}")]
-    InvalidByte { a: u8, b: u8, index: usize },
+    ///
+    /// `msb` and `lsb` are the characters of the pair encoding the most and
+    /// least significant nibble, `index` is the offset of `msb` in the input
+    #[error(
+        "invalid characters `{}{}` encountered at index {index}",
+        printable(*.msb),
+        printable(*.lsb)
+    )]
+    InvalidByte { msb: u8, lsb: u8, index: usize },
+    /// The input didn't start with `0x` or `0X`
+    #[error("missing `0x` prefix")]
+    MissingPrefix,
+    /// A separator was found where a hex character was expected
+    #[error("unexpected separator at index {position}")]
+    UnexpectedSeparator { position: usize },
+    /// A separator was expected, but something else was found
+    #[error("missing separator at index {position}")]
+    MissingSeparator { position: usize },
 }
 
 /// An error that may occur when parsing hex strings
 #[allow(clippy::module_name_repetitions)]
 #[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[non_exhaustive]
 pub enum HexStringError {
     /// The input didn't have required length
     #[error("non-even input length, encountered: `{encountered}`")]
     InvalidLength { encountered: usize },
     /// The input contained invalid character
-    #[error("invalid byte `{a:02x}{b:02x}` encountered at index {index}")]
-    InvalidByte { a: u8, b: u8, index: usize },
+    ///
+    /// `msb` and `lsb` are the characters of the pair encoding the most and
+    /// least significant nibble, `index` is the offset of `msb` in the input
+    #[error(
+        "invalid characters `{}{}` encountered at index {index}",
+        printable(*.msb),
+        printable(*.lsb)
+    )]
+    InvalidByte { msb: u8, lsb: u8, index: usize },
+    /// The input didn't start with `0x` or `0X`
+    #[error("missing `0x` prefix")]
+    MissingPrefix,
+    /// A separator was found where a hex character was expected
+    #[error("unexpected separator at index {position}")]
+    UnexpectedSeparator { position: usize },
+    /// A separator was expected, but something else was found
+    #[error("missing separator at index {position}")]
+    MissingSeparator { position: usize },
+}
+
+/// An error that may occur when the length of a buffer doesn't match the
+/// required length
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("invalid buffer length, expected `{expected}`, encountered: `{encountered}`")]
+pub struct LengthError {
+    pub expected: usize,
+    pub encountered: usize,
+}
+
+/// Display `v` as a character if it's printable ASCII, as `\xNN` otherwise
+fn printable(v: u8) -> impl core::fmt::Display {
+    struct Printable(u8);
+
+    impl core::fmt::Display for Printable {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            if self.0.is_ascii_graphic() {
+                write!(f, "{}", char::from(self.0))
+            } else {
+                write!(f, "\\x{:02x}", self.0)
+            }
+        }
+    }
+
+    Printable(v)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{HexStringError, HexStringNError};
+
+    #[test]
+    fn invalid_byte_display() {
+        let err = HexStringError::InvalidByte {
+            msb: b'z',
+            lsb: b'q',
+            index: 6,
+        };
+        assert_eq!(
+            err.to_string(),
+            "invalid characters `zq` encountered at index 6"
+        );
+
+        let err = HexStringNError::InvalidByte {
+            msb: b'1',
+            lsb: b'g',
+            index: 0,
+        };
+        assert_eq!(
+            err.to_string(),
+            "invalid characters `1g` encountered at index 0"
+        );
+
+        let err = HexStringError::InvalidByte {
+            msb: b'Z',
+            lsb: 0xff,
+            index: 2,
+        };
+        assert_eq!(
+            err.to_string(),
+            "invalid characters `Z\\xff` encountered at index 2"
+        );
+
+        let err = HexStringError::InvalidByte {
+            msb: b' ',
+            lsb: b'\n',
+            index: 4,
+        };
+        assert_eq!(
+            err.to_string(),
+            "invalid characters `\\x20\\x0a` encountered at index 4"
+        );
+    }
 }
@@ -0,0 +1,343 @@
+#[cfg(feature = "alloc")]
+use alloc::{string::String, vec::Vec};
+
+use crate::{utils, HexStringNError};
+#[cfg(feature = "alloc")]
+use crate::{FromHex, HexStringError, ToHex};
+
+/// Encode `bytes` as a hex string, lowercase.
+///
+/// # Example:
+/// ```
+/// assert_eq!(hex_str::encode_hex_lower(&[0x1a, 0x2b]), "1a2b");
+/// ```
+#[cfg(feature = "alloc")]
+#[must_use]
+pub fn encode_hex_lower(bytes: &[u8]) -> String {
+    bytes.to_hex_lower()
+}
+
+/// Encode `bytes` as a hex string, uppercase.
+///
+/// # Example:
+/// ```
+/// assert_eq!(hex_str::encode_hex_upper(&[0x1a, 0x2b]), "1A2B");
+/// ```
+#[cfg(feature = "alloc")]
+#[must_use]
+pub fn encode_hex_upper(bytes: &[u8]) -> String {
+    bytes.to_hex_upper()
+}
+
+/// Decode `bytes` from a hex string, both lowercase and uppercase characters
+/// allowed.
+///
+/// # Errors
+/// - if `bytes.len() % 2 != 0`
+/// - if `bytes` contains characters other than `[0-9a-fA-F]`
+///
+/// # Example:
+/// ```
+/// use hex_str::HexStringError;
+///
+/// assert_eq!(hex_str::decode_hex("1a2B"), Ok(vec![0x1a, 0x2b]));
+/// assert_eq!(
+///     hex_str::decode_hex("1a2"),
+///     Err(HexStringError::InvalidLength { encountered: 3 })
+/// );
+/// ```
+#[cfg(feature = "alloc")]
+pub fn decode_hex(bytes: impl AsRef<[u8]>) -> Result<Vec<u8>, HexStringError> {
+    Vec::from_hex(bytes)
+}
+
+/// Check whether `bytes` is a valid hex string, both lowercase and uppercase
+/// characters allowed, without allocating.
+///
+/// # Example:
+/// ```
+/// assert!(hex_str::is_valid_hex_str("1a2B"));
+/// assert!(hex_str::is_valid_hex_str(""));
+/// assert!(!hex_str::is_valid_hex_str("1a2"));
+/// assert!(!hex_str::is_valid_hex_str("1a2g"));
+/// ```
+#[must_use]
+pub fn is_valid_hex_str(bytes: impl AsRef<[u8]>) -> bool {
+    is_valid(bytes.as_ref(), utils::parse)
+}
+
+/// Check whether `bytes` is a valid hex string, only lowercase characters
+/// allowed, without allocating.
+///
+/// # Example:
+/// ```
+/// assert!(hex_str::is_valid_lower_hex_str("1a2b"));
+/// assert!(!hex_str::is_valid_lower_hex_str("1a2B"));
+/// ```
+#[must_use]
+pub fn is_valid_lower_hex_str(bytes: impl AsRef<[u8]>) -> bool {
+    is_valid(bytes.as_ref(), utils::parse_lower)
+}
+
+/// Check whether `bytes` is a valid hex string, only uppercase characters
+/// allowed, without allocating.
+///
+/// # Example:
+/// ```
+/// assert!(hex_str::is_valid_upper_hex_str("1A2B"));
+/// assert!(!hex_str::is_valid_upper_hex_str("1a2B"));
+/// ```
+#[must_use]
+pub fn is_valid_upper_hex_str(bytes: impl AsRef<[u8]>) -> bool {
+    is_valid(bytes.as_ref(), utils::parse_upper)
+}
+
+fn is_valid(bytes: &[u8], conversion_fn: impl Fn(u8, u8) -> Option<u8>) -> bool {
+    bytes.len() % 2 == 0
+        && bytes
+            .chunks_exact(2)
+            .all(|v| conversion_fn(v[0], v[1]).is_some())
+}
+
+/// Lazily encode `bytes` as hex characters, lowercase.
+///
+/// Every input byte yields two characters, nothing is buffered.
+///
+/// # Example:
+/// ```
+/// let s: String = hex_str::encode_hex_lower_iter([0x1a, 0x2b]).collect();
+/// assert_eq!(s, "1a2b");
+/// ```
+pub fn encode_hex_lower_iter(bytes: impl IntoIterator<Item = u8>) -> impl Iterator<Item = char> {
+    encode_hex_lower_ascii_iter(bytes).map(char::from)
+}
+
+/// Lazily encode `bytes` as hex characters, uppercase.
+///
+/// Every input byte yields two characters, nothing is buffered.
+///
+/// # Example:
+/// ```
+/// let s: String = hex_str::encode_hex_upper_iter([0x1a, 0x2b]).collect();
+/// assert_eq!(s, "1A2B");
+/// ```
+pub fn encode_hex_upper_iter(bytes: impl IntoIterator<Item = u8>) -> impl Iterator<Item = char> {
+    encode_hex_upper_ascii_iter(bytes).map(char::from)
+}
+
+/// Lazily encode `bytes` as ASCII hex characters, lowercase.
+///
+/// Every input byte yields two characters, nothing is buffered.
+///
+/// # Example:
+/// ```
+/// let s: Vec<u8> = hex_str::encode_hex_lower_ascii_iter([0x1a, 0x2b]).collect();
+/// assert_eq!(s, b"1a2b");
+/// ```
+pub fn encode_hex_lower_ascii_iter(
+    bytes: impl IntoIterator<Item = u8>,
+) -> impl Iterator<Item = u8> {
+    bytes.into_iter().flat_map(utils::to_hex_lower)
+}
+
+/// Lazily encode `bytes` as ASCII hex characters, uppercase.
+///
+/// Every input byte yields two characters, nothing is buffered.
+///
+/// # Example:
+/// ```
+/// let s: Vec<u8> = hex_str::encode_hex_upper_ascii_iter([0x1a, 0x2b]).collect();
+/// assert_eq!(s, b"1A2B");
+/// ```
+pub fn encode_hex_upper_ascii_iter(
+    bytes: impl IntoIterator<Item = u8>,
+) -> impl Iterator<Item = u8> {
+    bytes.into_iter().flat_map(utils::to_hex_upper)
+}
+
+/// Decode `bytes` into an array of length `N`, both lowercase and uppercase
+/// characters allowed.
+///
+/// This is a `const fn`, so it can be used to parse hex literals at compile
+/// time, where an invalid literal fails the build.
+///
+/// # Errors
+/// - if `bytes.len() != 2 * N`
+/// - if `bytes` contains characters other than `[0-9a-fA-F]`
+///
+/// # Example:
+/// ```
+/// use hex_str::HexStringNError;
+///
+/// const KEY: [u8; 4] = match hex_str::decode_hex_array(b"deadBEEF") {
+///     Ok(v) => v,
+///     Err(_) => panic!("invalid hex literal"),
+/// };
+/// assert_eq!(KEY, [0xde, 0xad, 0xbe, 0xef]);
+///
+/// assert_eq!(
+///     hex_str::decode_hex_array::<2>(b"1a2g"),
+///     Err(HexStringNError::InvalidByte { msb: b'2', lsb: b'g', index: 2 })
+/// );
+/// ```
+///
+/// An invalid literal in a constant fails to compile:
+/// ```compile_fail
+/// const KEY: [u8; 1] = match hex_str::decode_hex_array(b"gg") {
+///     Ok(v) => v,
+///     Err(_) => panic!("invalid hex literal"),
+/// };
+/// ```
+pub const fn decode_hex_array<const N: usize>(bytes: &[u8]) -> Result<[u8; N], HexStringNError> {
+    if bytes.len() != N * 2 {
+        return Err(HexStringNError::InvalidLength {
+            expected: N * 2,
+            encountered: bytes.len(),
+        });
+    }
+
+    let mut ret = [0; N];
+    let mut i = 0;
+    while i < N {
+        let msb = bytes[i * 2];
+        let lsb = bytes[i * 2 + 1];
+        match (utils::parse_nibble(msb), utils::parse_nibble(lsb)) {
+            (Some(a), Some(b)) => ret[i] = (a << 4) | b,
+            _ => {
+                return Err(HexStringNError::InvalidByte {
+                    msb,
+                    lsb,
+                    index: i * 2,
+                })
+            }
+        }
+
+        i += 1;
+    }
+
+    Ok(ret)
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use super::{
+        decode_hex, decode_hex_array, encode_hex_lower, encode_hex_lower_ascii_iter,
+        encode_hex_lower_iter, encode_hex_upper, encode_hex_upper_ascii_iter,
+        encode_hex_upper_iter, is_valid_hex_str, is_valid_lower_hex_str, is_valid_upper_hex_str,
+    };
+    use crate::{HexStringError, HexStringN, HexStringNError};
+
+    #[test]
+    fn round_trip() {
+        let bytes: Vec<u8> = (0..=u8::MAX).collect();
+
+        assert_eq!(decode_hex(encode_hex_lower(&bytes)), Ok(bytes.clone()));
+        assert_eq!(decode_hex(encode_hex_upper(&bytes)), Ok(bytes.clone()));
+        assert_eq!(
+            encode_hex_upper(&bytes),
+            encode_hex_lower(&bytes).to_uppercase()
+        );
+        assert_eq!(decode_hex(""), Ok(vec![]));
+        assert_eq!(
+            decode_hex("1a2g"),
+            Err(HexStringError::InvalidByte {
+                msb: b'2',
+                lsb: b'g',
+                index: 2
+            })
+        );
+    }
+
+    #[test]
+    fn encode_iter() {
+        let bytes: Vec<u8> = (0..=u8::MAX).collect();
+
+        let lower: String = encode_hex_lower_iter(bytes.iter().copied()).collect();
+        assert_eq!(lower, encode_hex_lower(&bytes));
+        let upper: String = encode_hex_upper_iter(bytes.iter().copied()).collect();
+        assert_eq!(upper, encode_hex_upper(&bytes));
+        let ascii: Vec<u8> = encode_hex_lower_ascii_iter(bytes.iter().copied()).collect();
+        assert_eq!(ascii, lower.as_bytes());
+        let ascii: Vec<u8> = encode_hex_upper_ascii_iter(bytes.iter().copied()).collect();
+        assert_eq!(ascii, upper.as_bytes());
+
+        assert_eq!(encode_hex_lower_iter([]).count(), 0);
+        assert!(encode_hex_lower_iter([0xab]).eq(['a', 'b']));
+
+        // only as much of the input is consumed as is needed
+        let mut consumed = 0;
+        let mut iter = encode_hex_lower_iter((0..).inspect(|_| consumed += 1));
+        assert_eq!(iter.nth(2), Some('0'));
+        drop(iter);
+        assert_eq!(consumed, 2);
+    }
+
+    #[test]
+    fn decode_const() {
+        const V: [u8; 16] = match decode_hex_array(b"d41d8cd98f00b204e9800998ecf8427E") {
+            Ok(v) => v,
+            Err(_) => panic!(),
+        };
+        assert_eq!(
+            HexStringN::new(V),
+            HexStringN::<16>::try_parse("d41d8cd98f00b204e9800998ecf8427e").unwrap()
+        );
+
+        let bytes: Vec<u8> = (0..=u8::MAX).collect();
+        let decoded = decode_hex_array::<256>(encode_hex_upper(&bytes).as_bytes()).unwrap();
+        assert_eq!(decoded[..], bytes[..]);
+
+        assert_eq!(decode_hex_array::<0>(b""), Ok([]));
+        assert_eq!(
+            decode_hex_array::<2>(b"1a2"),
+            Err(HexStringNError::InvalidLength {
+                expected: 4,
+                encountered: 3
+            })
+        );
+        assert_eq!(
+            decode_hex_array::<2>(b"1a 2"),
+            Err(HexStringNError::InvalidByte {
+                msb: b' ',
+                lsb: b'2',
+                index: 2
+            })
+        );
+    }
+
+    #[test]
+    fn is_valid() {
+        let bytes: Vec<u8> = (0..=u8::MAX).collect();
+        let lower = encode_hex_lower(&bytes);
+        let upper = encode_hex_upper(&bytes);
+
+        let fns: [fn(&str) -> bool; 3] = [
+            |s| is_valid_hex_str(s),
+            |s| is_valid_lower_hex_str(s),
+            |s| is_valid_upper_hex_str(s),
+        ];
+        for f in fns {
+            assert!(f(""));
+            assert!(f("09"));
+            assert!(!f("0"));
+            assert!(!f("012"));
+            for s in ["g0123456", "01g23456", "0123456g", "01 23", "０1"] {
+                assert!(!f(s), "{s}");
+            }
+        }
+
+        assert!(is_valid_hex_str(&lower));
+        assert!(is_valid_hex_str(&upper));
+        assert!(is_valid_hex_str("aBcDeF"));
+        assert!(is_valid_lower_hex_str(&lower));
+        assert!(!is_valid_lower_hex_str(&upper));
+        assert!(!is_valid_lower_hex_str("abcdeF"));
+        assert!(is_valid_upper_hex_str(&upper));
+        assert!(!is_valid_upper_hex_str(&lower));
+        assert!(!is_valid_upper_hex_str("ABCDEf"));
+
+        for s in [&lower, &upper, "1a2", "1a2g"] {
+            assert_eq!(is_valid_hex_str(s), decode_hex(s).is_ok());
+        }
+    }
+}
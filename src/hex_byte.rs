@@ -0,0 +1,287 @@
+use core::{
+    fmt::{Debug, Display, LowerHex, UpperHex},
+    str::FromStr,
+};
+
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+
+use crate::{utils, HexStringNError};
+
+/// A single byte, displayed as two hex characters
+///
+/// ## Example:
+/// ```
+/// use hex_str::HexByte;
+///
+/// let a = HexByte(0x1a);
+/// assert_eq!(a, "1a");
+/// assert_eq!(a, 0x1a);
+///
+/// let b: HexByte = "1A".parse().unwrap();
+/// assert_eq!(a, b);
+/// ```
+#[repr(transparent)]
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy::FromBytes,
+        zerocopy::IntoBytes,
+        zerocopy::Immutable,
+        zerocopy::KnownLayout,
+        zerocopy::Unaligned
+    )
+)]
+pub struct HexByte(pub u8);
+
+impl HexByte {
+    /// Create a new `HexByte`.
+    ///
+    /// # Example:
+    /// ```
+    /// use hex_str::HexByte;
+    ///
+    /// let v = HexByte::new(0x1a);
+    /// assert_eq!(v, "1a");
+    /// ```
+    #[must_use]
+    pub const fn new(v: u8) -> Self {
+        Self(v)
+    }
+
+    /// Parse a pair of characters, most significant first, both lowercase
+    /// and uppercase characters allowed. Return `None` if either of them
+    /// isn't in `[0-9a-fA-F]`.
+    ///
+    /// # Example:
+    /// ```
+    /// use hex_str::HexByte;
+    ///
+    /// assert_eq!(HexByte::from_pair(b'A', b'b'), Some(HexByte(0xab)));
+    /// assert_eq!(HexByte::from_pair(b'g', b'h'), None);
+    /// ```
+    #[must_use]
+    pub fn from_pair(a: u8, b: u8) -> Option<Self> {
+        utils::parse(a, b).map(Self)
+    }
+
+    /// Return the string representation of `self`, lowercase.
+    ///
+    /// # Example:
+    /// ```
+    /// use hex_str::HexByte;
+    ///
+    /// assert_eq!(HexByte(0xab).to_lower(), "ab");
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    pub fn to_lower(self) -> String {
+        let [a, b] = utils::to_hex_lower(self.0);
+        [char::from(a), char::from(b)].into_iter().collect()
+    }
+
+    /// Return the string representation of `self`, uppercase.
+    ///
+    /// # Example:
+    /// ```
+    /// use hex_str::HexByte;
+    ///
+    /// assert_eq!(HexByte(0xab).to_upper(), "AB");
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    pub fn to_upper(self) -> String {
+        let [a, b] = utils::to_hex_upper(self.0);
+        [char::from(a), char::from(b)].into_iter().collect()
+    }
+
+    /// Try to parse `bytes`, both lowercase and uppercase characters allowed.
+    ///
+    /// # Errors
+    /// - if `bytes.len() != 2`
+    /// - if `bytes` contains characters other than `[0-9a-fA-F]`
+    ///
+    /// # Example:
+    /// ```
+    /// use hex_str::{HexByte, HexStringNError};
+    ///
+    /// assert_eq!(HexByte::try_parse("1A"), Ok(HexByte(0x1a)));
+    /// assert_eq!(
+    ///     HexByte::try_parse("1a2b"),
+    ///     Err(HexStringNError::InvalidLength { expected: 2, encountered: 4 })
+    /// );
+    /// ```
+    pub fn try_parse(bytes: impl AsRef<[u8]>) -> Result<Self, HexStringNError> {
+        match *bytes.as_ref() {
+            [a, b] => Self::from_pair(a, b).ok_or(HexStringNError::InvalidByte {
+                msb: a,
+                lsb: b,
+                index: 0,
+            }),
+            ref bytes => Err(HexStringNError::InvalidLength {
+                expected: 2,
+                encountered: bytes.len(),
+            }),
+        }
+    }
+}
+
+impl Display for HexByte {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.pad(as_str(&utils::to_hex_lower(self.0)))
+    }
+}
+
+impl LowerHex for HexByte {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.pad_integral(true, "0x", as_str(&utils::to_hex_lower(self.0)))
+    }
+}
+
+impl UpperHex for HexByte {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.pad_integral(true, "0x", as_str(&utils::to_hex_upper(self.0)))
+    }
+}
+
+impl Debug for HexByte {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("HexByte")
+            .field(&format_args!("\"{self}\""))
+            .finish()
+    }
+}
+
+impl FromStr for HexByte {
+    type Err = HexStringNError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_parse(s)
+    }
+}
+
+impl From<u8> for HexByte {
+    fn from(value: u8) -> Self {
+        Self(value)
+    }
+}
+
+impl From<HexByte> for u8 {
+    fn from(value: HexByte) -> Self {
+        value.0
+    }
+}
+
+impl PartialEq<u8> for HexByte {
+    fn eq(&self, other: &u8) -> bool {
+        self.0 == *other
+    }
+}
+
+impl PartialEq<str> for HexByte {
+    fn eq(&self, other: &str) -> bool {
+        Self::try_parse(other).is_ok_and(|v| v == *self)
+    }
+}
+
+impl PartialEq<&str> for HexByte {
+    fn eq(&self, other: &&str) -> bool {
+        self == *other
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl PartialEq<String> for HexByte {
+    fn eq(&self, other: &String) -> bool {
+        self == other.as_str()
+    }
+}
+
+// SAFETY: `HexByte` is `#[repr(transparent)]` over `u8`, which is `Pod`
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Zeroable for HexByte {}
+
+// SAFETY: `HexByte` is `#[repr(transparent)]` over `u8`, which is `Pod`
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Pod for HexByte {}
+
+fn as_str(v: &[u8; 2]) -> &str {
+    core::str::from_utf8(v).unwrap_or_else(|_| unreachable!())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::HexByte;
+
+    #[test]
+    fn display_parse() {
+        for v in 0..=u8::MAX {
+            let b = HexByte(v);
+            assert_eq!(b.to_string(), format!("{v:02x}"));
+            assert_eq!(format!("{b:X}"), format!("{v:02X}"));
+            assert_eq!(b.to_string().parse::<HexByte>(), Ok(b));
+            assert_eq!(format!("{b:X}").parse::<HexByte>(), Ok(b));
+            assert_eq!(u8::from(HexByte::from(v)), v);
+        }
+
+        assert_eq!(HexByte(0xab).to_string(), "ab");
+        assert_eq!(format!("{:#x}", HexByte(0xab)), "0xab");
+        assert_eq!(format!("{:>4}", HexByte(0xab)), "  ab");
+        assert!("GH".parse::<HexByte>().is_err());
+        assert!("a".parse::<HexByte>().is_err());
+        assert_ne!(HexByte(0xab), "abc");
+    }
+
+    #[cfg(feature = "bytemuck")]
+    #[test]
+    fn bytemuck() {
+        let bytes: [u8; 4] = [0x1a, 0x2b, 0x3c, 0x4d];
+
+        let v: [HexByte; 4] = bytemuck::cast(bytes);
+        assert_eq!(
+            v,
+            [HexByte(0x1a), HexByte(0x2b), HexByte(0x3c), HexByte(0x4d)]
+        );
+
+        let v: &[HexByte] = bytemuck::cast_slice(&bytes);
+        assert_eq!(
+            v,
+            [HexByte(0x1a), HexByte(0x2b), HexByte(0x3c), HexByte(0x4d)]
+        );
+
+        let v: &HexByte = bytemuck::cast_ref(&bytes[1]);
+        assert_eq!(*v, "2b");
+
+        let mut bytes = bytes;
+        let v: &mut [HexByte; 4] = bytemuck::cast_mut(&mut bytes);
+        v[0] = HexByte(0xff);
+        assert_eq!(bytes, [0xff, 0x2b, 0x3c, 0x4d]);
+
+        let v: [HexByte; 2] = bytemuck::Zeroable::zeroed();
+        assert_eq!(v, [HexByte(0); 2]);
+    }
+
+    #[cfg(feature = "zerocopy")]
+    #[test]
+    fn zerocopy() {
+        use zerocopy::{FromBytes, IntoBytes};
+
+        let mut bytes: [u8; 16] = core::array::from_fn(|i| u8::try_from(i * 0x11).unwrap());
+
+        let v = <[HexByte]>::ref_from_bytes(&bytes[..]).unwrap();
+        assert_eq!(v.len(), 16);
+        assert!(v.iter().zip(bytes).all(|(a, b)| *a == b));
+        assert_eq!(v.as_bytes(), bytes);
+
+        let v = <[HexByte; 16]>::ref_from_bytes(&bytes[..]).unwrap();
+        assert_eq!(v[15], "ff");
+
+        let v = <[HexByte; 16]>::mut_from_bytes(&mut bytes[..]).unwrap();
+        v[0] = HexByte(0xab);
+        assert_eq!(bytes[0], 0xab);
+
+        let v = zerocopy::Ref::<_, HexByte>::from_bytes(&bytes[1..2]).unwrap();
+        assert_eq!(*v, "11");
+    }
+}
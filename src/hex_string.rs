@@ -1,16 +1,27 @@
-use std::{
+use core::{
     borrow::{Borrow, BorrowMut},
-    fmt::{Debug, Display},
-    ops::{Deref, DerefMut},
+    cmp::Ordering,
+    fmt::{Debug, Display, LowerHex, UpperHex},
+    ops::{Deref, DerefMut, Not, RangeBounds},
     str::FromStr,
 };
 
-use crate::{utils, HexStringError};
+use alloc::{boxed::Box, collections::TryReserveError, string::String, sync::Arc, vec, vec::Vec};
+#[cfg(feature = "std")]
+use std::io;
+
+#[cfg(feature = "std")]
+use crate::HexParser;
+use crate::{utils, HexStringError, HexStringN, LengthError};
 
 /// A hex string of variable length
 ///
 /// For hex strings of constant length see [`HexStringN`](crate::HexStringN)
 ///
+/// `HexString` dereferences to `[u8]`, the same way `String` dereferences to
+/// `str`. Methods specific to `Vec<u8>` are available through
+/// [`HexString::as_mut_vec`].
+///
 /// ## Example:
 /// ```
 /// use hex_str::HexString;
@@ -24,7 +35,7 @@ use crate::{utils, HexStringError};
 /// assert_eq!(a, b);
 /// ```
 #[repr(transparent)]
-#[derive(Clone, PartialEq, Eq, Hash)]
+#[derive(Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct HexString(Vec<u8>);
 
 impl HexString {
@@ -43,6 +54,123 @@ impl HexString {
         Self(v.into())
     }
 
+    /// Create a new, empty `HexString` with at least `capacity` bytes
+    /// preallocated.
+    ///
+    /// See [`Vec::with_capacity`].
+    ///
+    /// # Example:
+    /// ```
+    /// use hex_str::HexString;
+    ///
+    /// let v = HexString::with_capacity(100);
+    /// assert!(v.is_empty());
+    /// assert!(v.capacity() >= 100);
+    /// ```
+    #[must_use]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self(Vec::with_capacity(capacity))
+    }
+
+    /// Return the number of bytes `self` can hold without reallocating.
+    ///
+    /// See [`Vec::capacity`].
+    ///
+    /// # Example:
+    /// ```
+    /// use hex_str::HexString;
+    ///
+    /// let mut v = HexString::with_capacity(100);
+    /// for i in 0..50 {
+    ///     v.push(i);
+    /// }
+    /// assert!(v.capacity() >= 100);
+    /// ```
+    #[must_use]
+    pub fn capacity(&self) -> usize {
+        self.0.capacity()
+    }
+
+    /// Reserve capacity for at least `additional` more bytes.
+    ///
+    /// See [`Vec::reserve`].
+    ///
+    /// # Panics
+    /// - if the new capacity exceeds `isize::MAX` bytes
+    ///
+    /// # Example:
+    /// ```
+    /// use hex_str::HexString;
+    ///
+    /// let mut v = HexString::new([0x1a]);
+    /// v.reserve(10);
+    /// assert!(v.capacity() >= 11);
+    /// ```
+    pub fn reserve(&mut self, additional: usize) {
+        self.0.reserve(additional);
+    }
+
+    /// Try to reserve capacity for at least `additional` more bytes.
+    ///
+    /// See [`Vec::try_reserve`].
+    ///
+    /// # Errors
+    /// - if the new capacity exceeds `isize::MAX` bytes, or the allocator
+    ///   reports a failure
+    ///
+    /// # Example:
+    /// ```
+    /// use hex_str::HexString;
+    ///
+    /// let mut v = HexString::new([0x1a]);
+    /// v.try_reserve(10).unwrap();
+    /// assert!(v.capacity() >= 11);
+    ///
+    /// assert!(v.try_reserve(usize::MAX).is_err());
+    /// ```
+    pub fn try_reserve(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        self.0.try_reserve(additional)
+    }
+
+    /// Try to reserve capacity for exactly `additional` more bytes.
+    ///
+    /// See [`Vec::try_reserve_exact`].
+    ///
+    /// # Errors
+    /// - if the new capacity exceeds `isize::MAX` bytes, or the allocator
+    ///   reports a failure
+    ///
+    /// # Example:
+    /// ```
+    /// use hex_str::HexString;
+    ///
+    /// let mut v = HexString::new([0x1a]);
+    /// v.try_reserve_exact(10).unwrap();
+    /// assert!(v.capacity() >= 11);
+    ///
+    /// assert!(v.try_reserve_exact(usize::MAX).is_err());
+    /// ```
+    pub fn try_reserve_exact(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        self.0.try_reserve_exact(additional)
+    }
+
+    /// Shrink the capacity of `self` as much as possible.
+    ///
+    /// See [`Vec::shrink_to_fit`].
+    ///
+    /// # Example:
+    /// ```
+    /// use hex_str::HexString;
+    ///
+    /// let mut v = HexString::with_capacity(100);
+    /// v.push(0x1a);
+    /// v.shrink_to_fit();
+    /// assert!(v.capacity() >= 1);
+    /// ```
+    pub fn shrink_to_fit(&mut self) {
+        self.0.shrink_to_fit();
+    }
+
     /// Convert `self` to its string representation, lowercase.
     ///
     /// # Example:
@@ -81,6 +209,116 @@ impl HexString {
             .collect()
     }
 
+    /// Write the string representation of `self` into `buf`, lowercase.
+    ///
+    /// Exactly `2 * self.len()` bytes are written to the beginning of `buf`,
+    /// the rest of `buf` is left untouched. Return the number of bytes
+    /// written.
+    ///
+    /// # Errors
+    /// - if `buf.len() < 2 * self.len()`
+    ///
+    /// # Example:
+    /// ```
+    /// use hex_str::{HexString, LengthError};
+    ///
+    /// let v = HexString::new([0x1a, 0x2b]);
+    ///
+    /// let mut buf = [0; 4];
+    /// assert_eq!(v.encode_lower_to_buf(&mut buf), Ok(4));
+    /// assert_eq!(std::str::from_utf8(&buf).unwrap(), v.to_lower());
+    ///
+    /// let mut buf = [0; 3];
+    /// let err = v.encode_lower_to_buf(&mut buf).unwrap_err();
+    /// assert_eq!(err, LengthError { expected: 4, encountered: 3 });
+    /// ```
+    pub fn encode_lower_to_buf(&self, buf: &mut [u8]) -> Result<usize, LengthError> {
+        utils::encode_to_buf(&self.0[..], buf, utils::to_hex_lower)
+    }
+
+    /// Write the string representation of `self` into `buf`, uppercase.
+    ///
+    /// Exactly `2 * self.len()` bytes are written to the beginning of `buf`,
+    /// the rest of `buf` is left untouched. Return the number of bytes
+    /// written.
+    ///
+    /// # Errors
+    /// - if `buf.len() < 2 * self.len()`
+    ///
+    /// # Example:
+    /// ```
+    /// use hex_str::HexString;
+    ///
+    /// let v = HexString::new([0x1a, 0x2b]);
+    ///
+    /// let mut buf = [0; 6];
+    /// assert_eq!(v.encode_upper_to_buf(&mut buf), Ok(4));
+    /// assert_eq!(&buf, b"1A2B\0\0");
+    /// assert_eq!(std::str::from_utf8(&buf[..4]).unwrap(), v.to_upper());
+    /// ```
+    pub fn encode_upper_to_buf(&self, buf: &mut [u8]) -> Result<usize, LengthError> {
+        utils::encode_to_buf(&self.0[..], buf, utils::to_hex_upper)
+    }
+
+    /// Write the string representation of `self` into `w`, lowercase.
+    ///
+    /// Unlike [`HexString::to_lower`] this doesn't allocate, characters are
+    /// written to `w` one by one.
+    ///
+    /// # Errors
+    /// - if writing to `w` fails
+    ///
+    /// # Example:
+    /// ```
+    /// use core::fmt::Write;
+    ///
+    /// use hex_str::HexString;
+    ///
+    /// struct Counter(usize);
+    ///
+    /// impl Write for Counter {
+    ///     fn write_str(&mut self, s: &str) -> core::fmt::Result {
+    ///         self.0 += s.len();
+    ///         Ok(())
+    ///     }
+    /// }
+    ///
+    /// let v = HexString::new([0x1a, 0x2b]);
+    ///
+    /// let mut s = String::new();
+    /// v.write_lower_to(&mut s).unwrap();
+    /// assert_eq!(s, v.to_lower());
+    ///
+    /// let mut counter = Counter(0);
+    /// v.write_lower_to(&mut counter).unwrap();
+    /// assert_eq!(counter.0, 4);
+    /// ```
+    pub fn write_lower_to(&self, w: &mut impl core::fmt::Write) -> core::fmt::Result {
+        utils::write_to(&self.0, w, utils::to_hex_lower)
+    }
+
+    /// Write the string representation of `self` into `w`, uppercase.
+    ///
+    /// Unlike [`HexString::to_upper`] this doesn't allocate, characters are
+    /// written to `w` one by one.
+    ///
+    /// # Errors
+    /// - if writing to `w` fails
+    ///
+    /// # Example:
+    /// ```
+    /// use hex_str::HexString;
+    ///
+    /// let v = HexString::new([0x1a, 0x2b]);
+    ///
+    /// let mut s = String::new();
+    /// v.write_upper_to(&mut s).unwrap();
+    /// assert_eq!(s, v.to_upper());
+    /// ```
+    pub fn write_upper_to(&self, w: &mut impl core::fmt::Write) -> core::fmt::Result {
+        utils::write_to(&self.0, w, utils::to_hex_upper)
+    }
+
     /// Try to parse `bytes`, both lowercase and uppercase characters allowed.
     ///
     /// This is the same as using [`HexString::from_str`]/[`str::parse`] but
@@ -115,7 +353,7 @@ impl HexString {
     /// assert_eq!(v.unwrap(), "1a2b3c4d");
     ///
     /// let v = HexString::try_parse_lower("1A2B3C4D");
-    /// assert_eq!(v.unwrap_err(), HexStringError::InvalidByte { a: b'1', b: b'A', index: 0 });
+    /// assert_eq!(v.unwrap_err(), HexStringError::InvalidByte { msb: b'1', lsb: b'A', index: 0 });
     pub fn try_parse_lower(bytes: impl AsRef<[u8]>) -> Result<Self, HexStringError> {
         try_parse(bytes, utils::parse_lower)
     }
@@ -134,11 +372,140 @@ impl HexString {
     /// assert_eq!(v.unwrap(), "1a2b3c4d");
     ///
     /// let v = HexString::try_parse_upper("1a2b3c4d");
-    /// assert_eq!(v.unwrap_err(), HexStringError::InvalidByte { a: b'1', b: b'a', index: 0 });
+    /// assert_eq!(v.unwrap_err(), HexStringError::InvalidByte { msb: b'1', lsb: b'a', index: 0 });
     pub fn try_parse_upper(bytes: impl AsRef<[u8]>) -> Result<Self, HexStringError> {
         try_parse(bytes, utils::parse_upper)
     }
 
+    /// Try to parse `bytes` prefixed with `0x` or `0X`, both lowercase and
+    /// uppercase characters allowed.
+    ///
+    /// The reported lengths and indices include the prefix.
+    ///
+    /// # Errors
+    /// - if `bytes` doesn't start with `0x` or `0X`
+    /// - if `bytes.len() % 2 != 0`
+    /// - if `bytes` contains characters other than `[0-9a-fA-F]` after the prefix
+    ///
+    /// # Example:
+    /// ```
+    /// use hex_str::{HexString, HexStringError};
+    ///
+    /// let v = HexString::try_parse_prefixed("0x1A2b");
+    /// assert_eq!(v.unwrap(), "1a2b");
+    ///
+    /// let v = HexString::try_parse_prefixed("0X1A2b");
+    /// assert_eq!(v.unwrap(), "1a2b");
+    ///
+    /// let v = HexString::try_parse_prefixed("0x");
+    /// assert!(v.unwrap().is_empty());
+    ///
+    /// let v = HexString::try_parse_prefixed("0X");
+    /// assert!(v.unwrap().is_empty());
+    ///
+    /// let v = HexString::try_parse_prefixed("1a2b");
+    /// assert_eq!(v.unwrap_err(), HexStringError::MissingPrefix);
+    /// ```
+    pub fn try_parse_prefixed(bytes: impl AsRef<[u8]>) -> Result<Self, HexStringError> {
+        try_parse_prefixed(bytes, utils::parse)
+    }
+
+    /// Try to parse `bytes` prefixed with `0x` or `0X`, only lowercase
+    /// characters allowed.
+    ///
+    /// Only the digits are required to be lowercase, the prefix may be either
+    /// `0x` or `0X`.
+    ///
+    /// # Errors
+    /// - if `bytes` doesn't start with `0x` or `0X`
+    /// - if `bytes.len() % 2 != 0`
+    /// - if `bytes` contains characters other than `[0-9a-f]` after the prefix
+    ///
+    /// # Example:
+    /// ```
+    /// use hex_str::{HexString, HexStringError};
+    ///
+    /// let v = HexString::try_parse_prefixed_lower("0x1a2b");
+    /// assert_eq!(v.unwrap(), "1a2b");
+    ///
+    /// let v = HexString::try_parse_prefixed_lower("0x1A2B");
+    /// assert_eq!(v.unwrap_err(), HexStringError::InvalidByte { msb: b'1', lsb: b'A', index: 2 });
+    /// ```
+    pub fn try_parse_prefixed_lower(bytes: impl AsRef<[u8]>) -> Result<Self, HexStringError> {
+        try_parse_prefixed(bytes, utils::parse_lower)
+    }
+
+    /// Try to parse `bytes` prefixed with `0x` or `0X`, only uppercase
+    /// characters allowed.
+    ///
+    /// Only the digits are required to be uppercase, the prefix may be either
+    /// `0x` or `0X`.
+    ///
+    /// # Errors
+    /// - if `bytes` doesn't start with `0x` or `0X`
+    /// - if `bytes.len() % 2 != 0`
+    /// - if `bytes` contains characters other than `[0-9A-F]` after the prefix
+    ///
+    /// # Example:
+    /// ```
+    /// use hex_str::{HexString, HexStringError};
+    ///
+    /// let v = HexString::try_parse_prefixed_upper("0x1A2B");
+    /// assert_eq!(v.unwrap(), "1a2b");
+    ///
+    /// let v = HexString::try_parse_prefixed_upper("0x1a2");
+    /// assert_eq!(v.unwrap_err(), HexStringError::InvalidLength { encountered: 5 });
+    /// ```
+    pub fn try_parse_prefixed_upper(bytes: impl AsRef<[u8]>) -> Result<Self, HexStringError> {
+        try_parse_prefixed(bytes, utils::parse_upper)
+    }
+
+    /// Return a slice containing the bytes of `self`.
+    ///
+    /// # Example:
+    /// ```
+    /// use hex_str::HexString;
+    ///
+    /// let v = HexString::new([0x1a, 0x2b]);
+    ///
+    /// assert_eq!(v.as_slice(), &[0x1a, 0x2b]);
+    /// ```
+    #[must_use]
+    pub fn as_slice(&self) -> &[u8] {
+        &self.0
+    }
+
+    /// Return a mutable slice containing the bytes of `self`.
+    ///
+    /// # Example:
+    /// ```
+    /// use hex_str::HexString;
+    ///
+    /// let mut v = HexString::new([0x1a, 0x2b]);
+    /// v.as_mut_slice()[0] = 0x00;
+    ///
+    /// assert_eq!(v, "002b");
+    /// ```
+    #[must_use]
+    pub fn as_mut_slice(&mut self) -> &mut [u8] {
+        &mut self.0
+    }
+
+    /// Return a reference to the inner vector.
+    ///
+    /// # Example:
+    /// ```
+    /// use hex_str::HexString;
+    ///
+    /// let v = HexString::new([0x1a, 0x2b]);
+    ///
+    /// assert_eq!(v.as_vec(), &vec![0x1a, 0x2b]);
+    /// ```
+    #[must_use]
+    pub fn as_vec(&self) -> &Vec<u8> {
+        &self.0
+    }
+
     /// Return a mutable reference to the inner array.
     ///
     /// # Example:
@@ -156,279 +523,2499 @@ impl HexString {
     pub fn as_mut_vec(&mut self) -> &mut Vec<u8> {
         &mut self.0
     }
-}
 
-fn try_parse(
-    bytes: impl AsRef<[u8]>,
-    conversion_fn: impl Fn(u8, u8) -> Option<u8>,
-) -> Result<HexString, HexStringError> {
-    let bytes = bytes.as_ref();
-    if bytes.len() % 2 != 0 {
-        return Err(HexStringError::InvalidLength {
-            encountered: bytes.len(),
-        });
+    /// Return an iterator over the bytes of `self`.
+    ///
+    /// # Example:
+    /// ```
+    /// use hex_str::HexString;
+    ///
+    /// let v = HexString::new([0x01, 0x02, 0x03]);
+    /// assert_eq!(v.iter().map(|&v| u32::from(v)).sum::<u32>(), 6);
+    /// ```
+    pub fn iter(&self) -> core::slice::Iter<'_, u8> {
+        self.0.iter()
     }
 
-    let mut ret = Vec::with_capacity(bytes.len() / 2);
-    let mut i = 0;
-    let mut j = 1;
-    for _ in 0..ret.capacity() {
-        let a = unsafe { *bytes.get_unchecked(i) };
-        let b = unsafe { *bytes.get_unchecked(j) };
-        conversion_fn(a, b)
-            .ok_or(HexStringError::InvalidByte { a, b, index: i })
-            .map(|w| ret.push(w))?;
-
-        // if len == usize::MAX, this will overflow after the last iteration
-        // which is fine
-        i = i.wrapping_add(2);
-        j = j.wrapping_add(2);
+    /// Return an iterator over the bytes of `self` that allows modifying
+    /// each byte.
+    ///
+    /// # Example:
+    /// ```
+    /// use hex_str::HexString;
+    ///
+    /// let mut v = HexString::new([0x00, 0xff]);
+    /// v.iter_mut().for_each(|v| *v ^= 0xaa);
+    /// assert_eq!(v, "aa55");
+    /// ```
+    pub fn iter_mut(&mut self) -> core::slice::IterMut<'_, u8> {
+        self.0.iter_mut()
     }
 
-    Ok(HexString::new(ret))
-}
-
-impl Display for HexString {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        Display::fmt(&self.to_lower(), f)
+    /// Create a new `HexString` from a [`HexStringN`], without copying.
+    ///
+    /// # Example:
+    /// ```
+    /// use hex_str::{HexString, HexStringN};
+    ///
+    /// let v = HexString::from_hex_string_n(HexStringN::new([0x1a, 0x2b]));
+    /// assert_eq!(v, "1a2b");
+    /// ```
+    #[must_use]
+    pub fn from_hex_string_n<const N: usize>(v: HexStringN<N>) -> Self {
+        Self::new(v.into_vec())
     }
-}
 
-impl Debug for HexString {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("HexString")
-            .field("inner", &self.to_string())
-            .finish()
+    /// Append `byte` to the end of `self`.
+    ///
+    /// See [`Vec::push`].
+    ///
+    /// # Example:
+    /// ```
+    /// use hex_str::HexString;
+    ///
+    /// let mut v = HexString::new([0x1a]);
+    /// v.push(0x2b);
+    /// assert_eq!(v, "1a2b");
+    /// ```
+    pub fn push(&mut self, byte: u8) {
+        self.0.push(byte);
     }
-}
-
-impl FromStr for HexString {
-    type Err = HexStringError;
 
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Self::try_parse(s)
-    }
+    /// Remove the last byte of `self` and return it, or `None` if `self` is
+    /// empty.
+    ///
+    /// See [`Vec::pop`].
+    ///
+    /// # Example:
+    /// ```
+    /// use hex_str::HexString;
+    ///
+    /// let mut v = HexString::new([0x1a, 0x2b]);
+    /// assert_eq!(v.pop(), Some(0x2b));
+    /// assert_eq!(v.pop(), Some(0x1a));
+    /// assert_eq!(v.pop(), None);
+    /// ```
+    pub fn pop(&mut self) -> Option<u8> {
+        self.0.pop()
+    }
+
+    /// Remove all bytes from `self`.
+    ///
+    /// See [`Vec::clear`].
+    ///
+    /// # Example:
+    /// ```
+    /// use hex_str::HexString;
+    ///
+    /// let mut v = HexString::new([0x1a, 0x2b]);
+    /// v.clear();
+    /// assert!(v.is_empty());
+    /// ```
+    pub fn clear(&mut self) {
+        self.0.clear();
+    }
+
+    /// Shorten `self` to `len` bytes, does nothing if `len` is greater than
+    /// the current length.
+    ///
+    /// See [`Vec::truncate`].
+    ///
+    /// # Example:
+    /// ```
+    /// use hex_str::HexString;
+    ///
+    /// let mut v = HexString::new([0x1a, 0x2b, 0x3c]);
+    /// v.truncate(4);
+    /// assert_eq!(v, "1a2b3c");
+    /// v.truncate(1);
+    /// assert_eq!(v, "1a");
+    /// ```
+    pub fn truncate(&mut self, len: usize) {
+        self.0.truncate(len);
+    }
+
+    /// Remove the bytes in `range` from `self` and return them as an
+    /// iterator.
+    ///
+    /// See [`Vec::drain`].
+    ///
+    /// # Panics
+    /// - if the start of `range` is greater than its end
+    /// - if the end of `range` is greater than `self.len()`
+    ///
+    /// # Example:
+    /// ```
+    /// use hex_str::HexString;
+    ///
+    /// let mut v = HexString::new([0x1a, 0x2b, 0x3c, 0x4d]);
+    /// let drained: Vec<u8> = v.drain(1..3).collect();
+    /// assert_eq!(drained, [0x2b, 0x3c]);
+    /// assert_eq!(v, "1a4d");
+    /// ```
+    pub fn drain(&mut self, range: impl RangeBounds<usize>) -> alloc::vec::Drain<'_, u8> {
+        self.0.drain(range)
+    }
+
+    /// Retain only the bytes for which `f` returns `true`.
+    ///
+    /// See [`Vec::retain`].
+    ///
+    /// # Example:
+    /// ```
+    /// use hex_str::HexString;
+    ///
+    /// let mut v = HexString::new([0x1a, 0x2b, 0x3c, 0x4d]);
+    /// v.retain(|v| v % 2 == 0);
+    /// assert_eq!(v, "1a3c");
+    /// ```
+    pub fn retain(&mut self, mut f: impl FnMut(u8) -> bool) {
+        self.0.retain(|&v| f(v));
+    }
+
+    /// Move all bytes of `other` to the end of `self`, leaving `other` empty.
+    ///
+    /// See [`Vec::append`].
+    ///
+    /// # Example:
+    /// ```
+    /// use hex_str::HexString;
+    ///
+    /// let mut a = HexString::new([0x1a, 0x2b]);
+    /// let mut b = HexString::new([0x3c]);
+    /// a.append(&mut b);
+    /// assert_eq!(a, "1a2b3c");
+    /// assert!(b.is_empty());
+    /// ```
+    pub fn append(&mut self, other: &mut HexString) {
+        self.0.append(&mut other.0);
+    }
+
+    /// Convert `self` into a [`HexStringN`], returning `self` back if its
+    /// length isn't `N`.
+    ///
+    /// # Errors
+    /// - if `self.len() != N`, the original `HexString` is returned
+    ///
+    /// # Example:
+    /// ```
+    /// use hex_str::HexString;
+    ///
+    /// let v = HexString::new([0x1a, 0x2b]);
+    /// assert_eq!(v.clone().try_into_array::<2>().unwrap(), "1a2b");
+    /// assert_eq!(v.clone().try_into_array::<3>().unwrap_err(), v);
+    /// ```
+    pub fn try_into_array<const N: usize>(self) -> Result<HexStringN<N>, HexString> {
+        self.try_into()
+    }
+
+    /// Return a reference to the bytes of `self` as an array, or `None` if
+    /// its length isn't `N`.
+    ///
+    /// # Example:
+    /// ```
+    /// use hex_str::HexString;
+    ///
+    /// let v = HexString::new([0x1a, 0x2b]);
+    /// assert_eq!(v.try_as_array::<2>(), Some(&[0x1a, 0x2b]));
+    /// assert_eq!(v.try_as_array::<3>(), None);
+    /// ```
+    #[must_use]
+    pub fn try_as_array<const N: usize>(&self) -> Option<&[u8; N]> {
+        self.0.as_slice().try_into().ok()
+    }
+
+    /// Parse `bytes` and append the result to `self`, both lowercase and
+    /// uppercase characters allowed.
+    ///
+    /// Reported lengths and indices are relative to the start of the whole
+    /// hex string, that is, they're offset by `2 * self.len()`. On failure
+    /// `self` is left unchanged.
+    ///
+    /// # Errors
+    /// - if `bytes.len() % 2 != 0`
+    /// - if `bytes` contains characters other than `[0-9a-fA-F]`
+    ///
+    /// # Example:
+    /// ```
+    /// use hex_str::{HexString, HexStringError};
+    ///
+    /// let mut v = HexString::new([0x1a, 0x2b]);
+    ///
+    /// v.push_str("3C4d").unwrap();
+    /// assert_eq!(v, "1a2b3c4d");
+    ///
+    /// let err = v.push_str("5e6g").unwrap_err();
+    /// assert_eq!(err, HexStringError::InvalidByte { msb: b'6', lsb: b'g', index: 10 });
+    /// assert_eq!(v, "1a2b3c4d");
+    /// ```
+    pub fn push_str(&mut self, bytes: impl AsRef<[u8]>) -> Result<(), HexStringError> {
+        push_str(&mut self.0, bytes, utils::parse)
+    }
+
+    /// Parse `bytes` and append the result to `self`, only lowercase
+    /// characters allowed.
+    ///
+    /// Reported lengths and indices are relative to the start of the whole
+    /// hex string, that is, they're offset by `2 * self.len()`. On failure
+    /// `self` is left unchanged.
+    ///
+    /// # Errors
+    /// - if `bytes.len() % 2 != 0`
+    /// - if `bytes` contains characters other than `[0-9a-f]`
+    ///
+    /// # Example:
+    /// ```
+    /// use hex_str::{HexString, HexStringError};
+    ///
+    /// let mut v = HexString::new([0x1a]);
+    ///
+    /// v.push_str_lower("2b").unwrap();
+    /// assert_eq!(v, "1a2b");
+    ///
+    /// let err = v.push_str_lower("3C").unwrap_err();
+    /// assert_eq!(err, HexStringError::InvalidByte { msb: b'3', lsb: b'C', index: 4 });
+    /// ```
+    pub fn push_str_lower(&mut self, bytes: impl AsRef<[u8]>) -> Result<(), HexStringError> {
+        push_str(&mut self.0, bytes, utils::parse_lower)
+    }
+
+    /// Parse `bytes` and append the result to `self`, only uppercase
+    /// characters allowed.
+    ///
+    /// Reported lengths and indices are relative to the start of the whole
+    /// hex string, that is, they're offset by `2 * self.len()`. On failure
+    /// `self` is left unchanged.
+    ///
+    /// # Errors
+    /// - if `bytes.len() % 2 != 0`
+    /// - if `bytes` contains characters other than `[0-9A-F]`
+    ///
+    /// # Example:
+    /// ```
+    /// use hex_str::{HexString, HexStringError};
+    ///
+    /// let mut v = HexString::new([0x1a]);
+    ///
+    /// v.push_str_upper("2B").unwrap();
+    /// assert_eq!(v, "1a2b");
+    ///
+    /// let err = v.push_str_upper("3C4").unwrap_err();
+    /// assert_eq!(err, HexStringError::InvalidLength { encountered: 7 });
+    /// ```
+    pub fn push_str_upper(&mut self, bytes: impl AsRef<[u8]>) -> Result<(), HexStringError> {
+        push_str(&mut self.0, bytes, utils::parse_upper)
+    }
+
+    /// Remove the last `N` bytes of `self` and return them as a
+    /// [`HexStringN`], or `None` if `self` is shorter than `N`.
+    ///
+    /// # Example:
+    /// ```
+    /// use hex_str::HexString;
+    ///
+    /// let mut v = HexString::new([0x1a, 0x2b, 0x3c]);
+    /// assert_eq!(v.pop_chunk::<2>().unwrap(), "2b3c");
+    /// assert_eq!(v, "1a");
+    /// assert_eq!(v.pop_chunk::<2>(), None);
+    /// assert_eq!(v, "1a");
+    /// ```
+    pub fn pop_chunk<const N: usize>(&mut self) -> Option<HexStringN<N>> {
+        let at = self.0.len().checked_sub(N)?;
+        let tail = self.0.split_off(at);
+
+        Some(HexStringN::try_from(tail).unwrap_or_else(|_| unreachable!()))
+    }
+
+    /// Append all bytes of `chunk` to the end of `self`.
+    ///
+    /// # Example:
+    /// ```
+    /// use hex_str::{HexString, HexStringN};
+    ///
+    /// let mut v = HexString::new([0x1a]);
+    /// v.push_chunk(&HexStringN::new([0x2b, 0x3c]));
+    /// assert_eq!(v, "1a2b3c");
+    /// ```
+    pub fn push_chunk<const N: usize>(&mut self, chunk: &HexStringN<N>) {
+        self.0.extend_from_slice(chunk.as_array());
+    }
+
+    /// Return a new `HexString` with `f` applied to every byte of `self`.
+    ///
+    /// # Example:
+    /// ```
+    /// use hex_str::HexString;
+    ///
+    /// let v = HexString::new([0x1a, 0x2b]);
+    /// assert_eq!(v.map(|v| v ^ 0xaa), "b081");
+    /// ```
+    #[must_use]
+    pub fn map(&self, f: impl FnMut(u8) -> u8) -> HexString {
+        Self(self.0.iter().copied().map(f).collect())
+    }
+
+    /// Apply `f` to every byte of `self` in place.
+    ///
+    /// # Example:
+    /// ```
+    /// use hex_str::HexString;
+    ///
+    /// let mut v = HexString::new([0x1a, 0x2b]);
+    /// v.map_inplace(|v| v.wrapping_add(1));
+    /// assert_eq!(v, "1b2c");
+    /// ```
+    pub fn map_inplace(&mut self, mut f: impl FnMut(u8) -> u8) {
+        self.0.iter_mut().for_each(|v| *v = f(*v));
+    }
+
+    /// Return an iterator over the nibbles of every byte of `self`, as
+    /// `(most significant, least significant)` pairs, each in `0..=15`.
+    ///
+    /// # Example:
+    /// ```
+    /// use hex_str::HexString;
+    ///
+    /// let v = HexString::new([0xab, 0x1c]);
+    /// let pairs: Vec<_> = v.iter_hex_pairs().collect();
+    /// assert_eq!(pairs, [(0x0a, 0x0b), (0x01, 0x0c)]);
+    /// ```
+    #[must_use]
+    pub fn iter_hex_pairs(&self) -> impl ExactSizeIterator<Item = (u8, u8)> + '_ {
+        self.0.iter().map(|&v| utils::split_nibbles(v))
+    }
+
+    /// Return an iterator over the nibbles of `self`, each in `0..=15`, most
+    /// significant nibble of every byte first.
+    ///
+    /// # Example:
+    /// ```
+    /// use hex_str::HexString;
+    ///
+    /// let v = HexString::new([0xab, 0x1c]);
+    /// let nibbles: Vec<_> = v.iter_nibbles().collect();
+    /// assert_eq!(nibbles, [0x0a, 0x0b, 0x01, 0x0c]);
+    /// ```
+    #[must_use]
+    pub fn iter_nibbles(&self) -> impl DoubleEndedIterator<Item = u8> + '_ {
+        self.0.iter().flat_map(|&v| {
+            let (a, b) = utils::split_nibbles(v);
+            [a, b]
+        })
+    }
+
+    /// Return a view of `self` as a slice of arrays of length `M`, or `None`
+    /// if `self.len()` isn't divisible by `M`.
+    ///
+    /// This is a zero-copy cast, `M` being non-zero is checked at compile
+    /// time.
+    ///
+    /// # Example:
+    /// ```
+    /// use hex_str::HexString;
+    ///
+    /// let v: HexString = "1a2b3c4d5e6f".parse().unwrap();
+    ///
+    /// let chunks = v.try_chunks_as::<2>().unwrap();
+    /// assert_eq!(chunks, [[0x1a, 0x2b], [0x3c, 0x4d], [0x5e, 0x6f]]);
+    ///
+    /// assert_eq!(v.try_chunks_as::<4>(), None);
+    /// ```
+    #[must_use]
+    pub fn try_chunks_as<const M: usize>(&self) -> Option<&[[u8; M]]> {
+        const { assert!(M != 0, "`M` must be non-zero") };
+
+        if self.0.len() % M != 0 {
+            return None;
+        }
+
+        // Safety: `[u8]` of length `k * M` has the same layout as
+        // `[[u8; M]]` of length `k`
+        Some(unsafe { core::slice::from_raw_parts(self.0.as_ptr().cast(), self.0.len() / M) })
+    }
+
+    /// Return a mutable view of `self` as a slice of arrays of length `M`, or
+    /// `None` if `self.len()` isn't divisible by `M`.
+    ///
+    /// This is a zero-copy cast, `M` being non-zero is checked at compile
+    /// time.
+    ///
+    /// # Example:
+    /// ```
+    /// use hex_str::HexString;
+    ///
+    /// let mut v: HexString = "1a2b3c4d5e6f".parse().unwrap();
+    ///
+    /// for chunk in v.try_chunks_as_mut::<3>().unwrap() {
+    ///     chunk.reverse();
+    /// }
+    /// assert_eq!(v, "3c2b1a6f5e4d");
+    ///
+    /// assert_eq!(v.try_chunks_as_mut::<4>(), None);
+    /// ```
+    #[must_use]
+    pub fn try_chunks_as_mut<const M: usize>(&mut self) -> Option<&mut [[u8; M]]> {
+        const { assert!(M != 0, "`M` must be non-zero") };
+
+        if self.0.len() % M != 0 {
+            return None;
+        }
+
+        // Safety: `[u8]` of length `k * M` has the same layout as
+        // `[[u8; M]]` of length `k`
+        Some(unsafe {
+            core::slice::from_raw_parts_mut(self.0.as_mut_ptr().cast(), self.0.len() / M)
+        })
+    }
+
+    /// Return a view of the first `self.len() / M * M` bytes of `self` as a
+    /// slice of arrays of length `M`. Like [`slice::chunks_exact`], trailing
+    /// bytes that don't fill a whole chunk are left out.
+    ///
+    /// This is a zero-copy cast, `M` being non-zero is checked at compile
+    /// time.
+    ///
+    /// # Example:
+    /// ```
+    /// use hex_str::HexString;
+    ///
+    /// let v: HexString = "1a2b3c4d5e".parse().unwrap();
+    ///
+    /// let chunks = v.chunks_exact_as::<2>();
+    /// assert_eq!(chunks, [[0x1a, 0x2b], [0x3c, 0x4d]]);
+    ///
+    /// for chunk in v.chunks_exact_as::<3>() {
+    ///     assert_eq!(chunk, &[0x1a, 0x2b, 0x3c]);
+    /// }
+    /// ```
+    #[must_use]
+    pub fn chunks_exact_as<const M: usize>(&self) -> &[[u8; M]] {
+        const { assert!(M != 0, "`M` must be non-zero") };
+
+        // Safety: the first `k * M` bytes of `[u8]` have the same layout as
+        // `[[u8; M]]` of length `k`
+        unsafe { core::slice::from_raw_parts(self.0.as_ptr().cast(), self.0.len() / M) }
+    }
+
+    /// Return a new `HexString` with `f` applied to every pair of bytes of
+    /// `self` and `other`.
+    ///
+    /// # Errors
+    /// - if `other.len() != self.len()`
+    ///
+    /// # Example:
+    /// ```
+    /// use hex_str::{HexString, LengthError};
+    ///
+    /// let v = HexString::new([0x1a, 0x2b]);
+    /// assert_eq!(v.zip_with(&[0xff, 0x0f], |a, b| a & b).unwrap(), "1a0b");
+    /// assert_eq!(
+    ///     v.zip_with(&[0xff], |a, b| a & b),
+    ///     Err(LengthError { expected: 2, encountered: 1 })
+    /// );
+    /// ```
+    pub fn zip_with(
+        &self,
+        other: &[u8],
+        mut f: impl FnMut(u8, u8) -> u8,
+    ) -> Result<HexString, LengthError> {
+        if other.len() != self.0.len() {
+            return Err(LengthError {
+                expected: self.0.len(),
+                encountered: other.len(),
+            });
+        }
+
+        Ok(Self(
+            self.0.iter().zip(other).map(|(&a, &b)| f(a, b)).collect(),
+        ))
+    }
+
+    /// Apply `f` to every pair of bytes of `self` and `other`, storing the
+    /// result in `self`.
+    ///
+    /// # Errors
+    /// - if `other.len() != self.len()`, `self` is left unchanged
+    ///
+    /// # Example:
+    /// ```
+    /// use hex_str::{HexString, LengthError};
+    ///
+    /// let mut v = HexString::new([0x1a, 0x2b]);
+    /// v.zip_with_inplace(&[0xff, 0xff], |a, b| a ^ b).unwrap();
+    /// assert_eq!(v, "e5d4");
+    /// assert_eq!(
+    ///     v.zip_with_inplace(&[], |a, b| a ^ b),
+    ///     Err(LengthError { expected: 2, encountered: 0 })
+    /// );
+    /// ```
+    pub fn zip_with_inplace(
+        &mut self,
+        other: &[u8],
+        mut f: impl FnMut(u8, u8) -> u8,
+    ) -> Result<(), LengthError> {
+        if other.len() != self.0.len() {
+            return Err(LengthError {
+                expected: self.0.len(),
+                encountered: other.len(),
+            });
+        }
+
+        self.0
+            .iter_mut()
+            .zip(other)
+            .for_each(|(a, &b)| *a = f(*a, b));
+
+        Ok(())
+    }
+
+    /// Return the bitwise XOR of `self` and `other`.
+    ///
+    /// # Errors
+    /// - if `other.len() != self.len()`
+    ///
+    /// # Example:
+    /// ```
+    /// use hex_str::{HexString, LengthError};
+    ///
+    /// let v = HexString::new([0x1a, 0x2b]);
+    /// assert_eq!(v.xor(&[0xff, 0x0f]).unwrap(), "e524");
+    /// assert_eq!(
+    ///     v.xor(&[0x0f]),
+    ///     Err(LengthError { expected: 2, encountered: 1 })
+    /// );
+    /// ```
+    pub fn xor(&self, other: &[u8]) -> Result<HexString, LengthError> {
+        self.zip_with(other, |a, b| a ^ b)
+    }
+
+    /// Return the bitwise AND of `self` and `other`.
+    ///
+    /// # Errors
+    /// - if `other.len() != self.len()`
+    ///
+    /// # Example:
+    /// ```
+    /// use hex_str::{HexString, LengthError};
+    ///
+    /// let v = HexString::new([0x1a, 0x2b]);
+    /// assert_eq!(v.and(&[0xf0, 0x0f]).unwrap(), "100b");
+    /// assert_eq!(
+    ///     v.and(&[0x0f]),
+    ///     Err(LengthError { expected: 2, encountered: 1 })
+    /// );
+    /// ```
+    pub fn and(&self, other: &[u8]) -> Result<HexString, LengthError> {
+        self.zip_with(other, |a, b| a & b)
+    }
+
+    /// Return the bitwise OR of `self` and `other`.
+    ///
+    /// # Errors
+    /// - if `other.len() != self.len()`
+    ///
+    /// # Example:
+    /// ```
+    /// use hex_str::{HexString, LengthError};
+    ///
+    /// let v = HexString::new([0x1a, 0x2b]);
+    /// assert_eq!(v.or(&[0xf0, 0x0f]).unwrap(), "fa2f");
+    /// assert_eq!(
+    ///     v.or(&[0x0f]),
+    ///     Err(LengthError { expected: 2, encountered: 1 })
+    /// );
+    /// ```
+    pub fn or(&self, other: &[u8]) -> Result<HexString, LengthError> {
+        self.zip_with(other, |a, b| a | b)
+    }
+
+    /// Try to parse `bytes`, both lowercase and uppercase characters allowed,
+    /// collecting every invalid pair instead of stopping at the first one.
+    ///
+    /// # Errors
+    /// - if `bytes.len() % 2 != 0`, the only error returned
+    /// - if `bytes` contains characters other than `[0-9a-fA-F]`, one error
+    ///   per invalid pair, in order of appearance
+    ///
+    /// # Example:
+    /// ```
+    /// use hex_str::{HexString, HexStringError};
+    ///
+    /// let v = HexString::try_parse_collect_errors("1a2b");
+    /// assert_eq!(v.unwrap(), "1a2b");
+    ///
+    /// let errors = HexString::try_parse_collect_errors("zz2b3cg0").unwrap_err();
+    /// assert_eq!(
+    ///     errors,
+    ///     [
+    ///         HexStringError::InvalidByte { msb: b'z', lsb: b'z', index: 0 },
+    ///         HexStringError::InvalidByte { msb: b'g', lsb: b'0', index: 6 },
+    ///     ]
+    /// );
+    /// ```
+    pub fn try_parse_collect_errors(bytes: impl AsRef<[u8]>) -> Result<Self, Vec<HexStringError>> {
+        let bytes = bytes.as_ref();
+        if bytes.len() % 2 != 0 {
+            return Err(vec![HexStringError::InvalidLength {
+                encountered: bytes.len(),
+            }]);
+        }
+
+        let mut errors = Vec::new();
+        let v = bytes
+            .chunks_exact(2)
+            .enumerate()
+            .map(|(i, pair)| {
+                let (msb, lsb) = (pair[0], pair[1]);
+                utils::parse(msb, lsb).unwrap_or_else(|| {
+                    errors.push(HexStringError::InvalidByte {
+                        msb,
+                        lsb,
+                        index: i * 2,
+                    });
+                    0
+                })
+            })
+            .collect();
+
+        if errors.is_empty() {
+            Ok(Self(v))
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Try to parse `bytes` consisting of pairs separated by `sep`, both
+    /// lowercase and uppercase characters allowed.
+    ///
+    /// Used for MAC addresses and fingerprints, such as `01:23:45:67:89:ab`.
+    ///
+    /// Every pair must be separated, so canonical `8-4-4-4-12` UUIDs such as
+    /// `67e55044-10b1-426f-9247-bb680e5fe0c8` are rejected, parse them with
+    /// `HexStringN::<16>::from_uuid_str` from the `uuid` feature instead.
+    ///
+    /// # Errors
+    /// - if `bytes` ends in the middle of a pair, or with `sep`
+    /// - if `sep` is found inside of a pair
+    /// - if pairs aren't separated by `sep`
+    /// - if `bytes` contains characters other than `[0-9a-fA-F]` inside of
+    ///   pairs
+    ///
+    /// # Example:
+    /// ```
+    /// use hex_str::{HexString, HexStringError};
+    ///
+    /// let v = HexString::try_parse_separated("01:23:45:67:89:AB", b':');
+    /// assert_eq!(v.unwrap(), "0123456789ab");
+    ///
+    /// let v = HexString::try_parse_separated("01 23 45", b' ');
+    /// assert_eq!(v.unwrap(), "012345");
+    ///
+    /// let v = HexString::try_parse_separated("01:2:345", b':');
+    /// assert_eq!(v.unwrap_err(), HexStringError::UnexpectedSeparator { position: 4 });
+    ///
+    /// let v = HexString::try_parse_separated("01:2345", b':');
+    /// assert_eq!(v.unwrap_err(), HexStringError::MissingSeparator { position: 5 });
+    ///
+    /// let v = HexString::try_parse_separated("01:23:", b':');
+    /// assert_eq!(v.unwrap_err(), HexStringError::InvalidLength { encountered: 6 });
+    /// ```
+    pub fn try_parse_separated(bytes: impl AsRef<[u8]>, sep: u8) -> Result<Self, HexStringError> {
+        let bytes = bytes.as_ref();
+        utils::parse_separated(bytes, sep, utils::parse)
+            .map(Self)
+            .map_err(|err| match err {
+                utils::SeparatedError::Truncated => HexStringError::InvalidLength {
+                    encountered: bytes.len(),
+                },
+                utils::SeparatedError::InvalidByte { msb, lsb, index } => {
+                    HexStringError::InvalidByte { msb, lsb, index }
+                }
+                utils::SeparatedError::UnexpectedSeparator { position } => {
+                    HexStringError::UnexpectedSeparator { position }
+                }
+                utils::SeparatedError::MissingSeparator { position } => {
+                    HexStringError::MissingSeparator { position }
+                }
+            })
+    }
+
+    /// Convert `self` to its string representation, lowercase, with `sep`
+    /// inserted after every `group_size` bytes.
+    ///
+    /// The last group is never followed by `sep`.
+    ///
+    /// # Panics
+    /// - if `group_size == 0`
+    ///
+    /// # Example:
+    /// ```
+    /// use hex_str::HexString;
+    ///
+    /// let v = HexString::new([0x1a, 0x2b, 0x3c, 0x4d]);
+    /// assert_eq!(v.format_separated(':', 1), "1a:2b:3c:4d");
+    /// assert_eq!(v.format_separated(' ', 2), "1a2b 3c4d");
+    /// assert_eq!(v.format_separated(' ', 3), "1a2b3c 4d");
+    /// ```
+    #[must_use]
+    pub fn format_separated(&self, sep: char, group_size: usize) -> String {
+        let mut ret = String::new();
+        self.write_separated_to(&mut ret, sep, group_size)
+            .unwrap_or_else(|_| unreachable!());
+        ret
+    }
+
+    /// Write the string representation of `self` into `w`, lowercase, with
+    /// `sep` inserted after every `group_size` bytes.
+    ///
+    /// Unlike [`HexString::format_separated`] this doesn't allocate.
+    ///
+    /// # Errors
+    /// - if writing to `w` fails
+    ///
+    /// # Panics
+    /// - if `group_size == 0`
+    ///
+    /// # Example:
+    /// ```
+    /// use hex_str::HexString;
+    ///
+    /// let v = HexString::new([0x1a, 0x2b, 0x3c, 0x4d]);
+    ///
+    /// let mut s = String::from("fingerprint: ");
+    /// v.write_separated_to(&mut s, ':', 1).unwrap();
+    /// assert_eq!(s, "fingerprint: 1a:2b:3c:4d");
+    /// ```
+    pub fn write_separated_to(
+        &self,
+        w: &mut impl core::fmt::Write,
+        sep: char,
+        group_size: usize,
+    ) -> core::fmt::Result {
+        utils::write_separated_to(&self.0[..], w, sep, group_size, utils::to_hex_lower)
+    }
+
+    /// Create a new `HexString` of `len` cryptographically secure random
+    /// bytes, from the operating system's source.
+    ///
+    /// # Errors
+    /// - if `getrandom` fails to provide random bytes
+    ///
+    /// # Example:
+    /// ```
+    /// use hex_str::HexString;
+    ///
+    /// let v = HexString::try_random(16).unwrap();
+    /// assert_eq!(v.len(), 16);
+    /// ```
+    #[cfg(feature = "getrandom")]
+    pub fn try_random(len: usize) -> Result<Self, getrandom::Error> {
+        let mut ret = vec![0; len];
+        getrandom::getrandom(&mut ret)?;
+
+        Ok(Self(ret))
+    }
+
+    /// Insert `byte` at `index`, shifting all bytes after it to the right.
+    ///
+    /// # Panics
+    /// - if `index > self.len()`
+    ///
+    /// # Example:
+    /// ```
+    /// use hex_str::HexString;
+    ///
+    /// let mut v = HexString::new([0x1a, 0x3c]);
+    /// v.insert(1, 0x2b);
+    /// assert_eq!(v, "1a2b3c");
+    /// ```
+    pub fn insert(&mut self, index: usize, byte: u8) {
+        self.0.insert(index, byte);
+    }
+
+    /// Remove and return the byte at `index`, shifting all bytes after it to
+    /// the left.
+    ///
+    /// # Panics
+    /// - if `index >= self.len()`
+    ///
+    /// # Example:
+    /// ```
+    /// use hex_str::HexString;
+    ///
+    /// let mut v = HexString::new([0x1a, 0x2b, 0x3c]);
+    /// assert_eq!(v.remove(1), 0x2b);
+    /// assert_eq!(v, "1a3c");
+    /// ```
+    pub fn remove(&mut self, index: usize) -> u8 {
+        self.0.remove(index)
+    }
+
+    /// Return a copy of `self` with the order of bytes reversed.
+    ///
+    /// Use [`slice::reverse`] to reverse in place.
+    ///
+    /// # Example:
+    /// ```
+    /// use hex_str::HexString;
+    ///
+    /// let v = HexString::new([0x1a, 0x2b, 0x3c]);
+    /// assert_eq!(v.reversed(), "3c2b1a");
+    /// ```
+    #[must_use]
+    pub fn reversed(&self) -> HexString {
+        Self(self.0.iter().rev().copied().collect())
+    }
+
+    /// Read hex text from `r` until EOF and parse it, both lowercase and
+    /// uppercase characters allowed. ASCII whitespace, including newlines,
+    /// is skipped.
+    ///
+    /// Invalid input is reported as an [`io::Error`] of kind
+    /// [`io::ErrorKind::InvalidData`], wrapping a [`HexStringError`] with the
+    /// index relative to the input with whitespace removed.
+    /// See [`HexDecoder`](crate::HexDecoder) for how to reach the wrapped error.
+    ///
+    /// # Errors
+    /// - if reading from `r` fails
+    /// - if the input, without whitespace, is of odd length
+    /// - if the input contains characters other than `[0-9a-fA-F]` and
+    ///   whitespace
+    ///
+    /// # Example:
+    /// ```
+    /// use std::io::{BufReader, ErrorKind};
+    ///
+    /// use hex_str::{HexString, HexStringError};
+    ///
+    /// let mut r = BufReader::new(&b"1a 2b\n3C 4d\n"[..]);
+    /// assert_eq!(HexString::from_reader(&mut r).unwrap(), "1a2b3c4d");
+    ///
+    /// let mut r = BufReader::new(&b"1a 2g\n"[..]);
+    /// let err = HexString::from_reader(&mut r).unwrap_err();
+    /// assert_eq!(err.kind(), ErrorKind::InvalidData);
+    /// assert_eq!(
+    ///     err.get_ref().unwrap().downcast_ref::<HexStringError>(),
+    ///     Some(&HexStringError::InvalidByte { msb: b'2', lsb: b'g', index: 2 })
+    /// );
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn from_reader(r: &mut impl io::BufRead) -> io::Result<Self> {
+        from_reader(r, true)
+    }
+
+    /// Read hex text from `r` until EOF and parse it, both lowercase and
+    /// uppercase characters allowed. Unlike [`HexString::from_reader`],
+    /// whitespace is rejected.
+    ///
+    /// Invalid input is reported as an [`io::Error`] of kind
+    /// [`io::ErrorKind::InvalidData`], wrapping a [`HexStringError`].
+    /// See [`HexDecoder`](crate::HexDecoder) for how to reach the wrapped error.
+    ///
+    /// # Errors
+    /// - if reading from `r` fails
+    /// - if the input is of odd length
+    /// - if the input contains characters other than `[0-9a-fA-F]`
+    ///
+    /// # Example:
+    /// ```
+    /// use std::io::BufReader;
+    ///
+    /// use hex_str::HexString;
+    ///
+    /// let mut r = BufReader::new(&b"1a2b3C4d"[..]);
+    /// assert_eq!(HexString::from_reader_exact(&mut r).unwrap(), "1a2b3c4d");
+    ///
+    /// let mut r = BufReader::new(&b"1a2b\n"[..]);
+    /// assert!(HexString::from_reader_exact(&mut r).is_err());
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn from_reader_exact(r: &mut impl io::BufRead) -> io::Result<Self> {
+        from_reader(r, false)
+    }
+
+    /// Try to parse every line of `input`, both lowercase and uppercase
+    /// characters allowed.
+    ///
+    /// Surrounding whitespace is trimmed, empty and whitespace-only lines are
+    /// skipped. Successfully parsed lines are returned in the first `Vec`,
+    /// failures in the second, together with their line number, starting at
+    /// `1`.
+    ///
+    /// # Example:
+    /// ```
+    /// use hex_str::{HexString, HexStringError};
+    ///
+    /// let (parsed, errors) = HexString::try_parse_lines("1a2b\n\n3C4d\n1g\n");
+    /// assert_eq!(parsed, ["1a2b", "3c4d"]);
+    /// assert_eq!(
+    ///     errors,
+    ///     [(4, HexStringError::InvalidByte { msb: b'1', lsb: b'g', index: 0 })]
+    /// );
+    /// ```
+    #[must_use]
+    pub fn try_parse_lines(input: &str) -> (Vec<Self>, Vec<(usize, HexStringError)>) {
+        let mut parsed = Vec::new();
+        let mut errors = Vec::new();
+        for (line, v) in lines(input) {
+            match Self::try_parse(v) {
+                Ok(v) => parsed.push(v),
+                Err(err) => errors.push((line, err)),
+            }
+        }
+
+        (parsed, errors)
+    }
+
+    /// Try to parse every line of `input`, both lowercase and uppercase
+    /// characters allowed, stopping at the first invalid line.
+    ///
+    /// Surrounding whitespace is trimmed, empty and whitespace-only lines are
+    /// skipped.
+    ///
+    /// # Errors
+    /// - if a line isn't a valid hex string, together with its line number,
+    ///   starting at `1`
+    ///
+    /// # Example:
+    /// ```
+    /// use hex_str::{HexString, HexStringError};
+    ///
+    /// let v = HexString::try_parse_lines_strict("1a2b\r\n3C4d\r\n");
+    /// assert_eq!(v.unwrap(), ["1a2b", "3c4d"]);
+    ///
+    /// let v = HexString::try_parse_lines_strict("1a2b\n3c4\n1g");
+    /// assert_eq!(v.unwrap_err(), (2, HexStringError::InvalidLength { encountered: 3 }));
+    /// ```
+    pub fn try_parse_lines_strict(input: &str) -> Result<Vec<Self>, (usize, HexStringError)> {
+        lines(input)
+            .map(|(line, v)| Self::try_parse(v).map_err(|err| (line, err)))
+            .collect()
+    }
+
+    /// Try to parse `bytes`, both lowercase and uppercase characters
+    /// allowed, ignoring any ASCII whitespace.
+    ///
+    /// Useful for hex dumps copied from terminals or documentation. Errors
+    /// are reported as if `bytes` had its whitespace removed.
+    ///
+    /// # Errors
+    /// - if `bytes` without whitespace isn't of a valid length
+    /// - if `bytes` contains characters other than `[0-9a-fA-F]` and
+    ///   whitespace
+    ///
+    /// # Example:
+    /// ```
+    /// use hex_str::{HexString, HexStringError};
+    ///
+    /// let v = HexString::try_parse_lax(" 1a 2B\n");
+    /// assert_eq!(v.unwrap(), "1a2b");
+    ///
+    /// let v = HexString::try_parse_lax("1a\t2");
+    /// assert_eq!(v.unwrap_err(), HexStringError::InvalidLength { encountered: 3 });
+    /// ```
+    pub fn try_parse_lax(bytes: impl AsRef<[u8]>) -> Result<Self, HexStringError> {
+        let bytes: Vec<u8> = bytes
+            .as_ref()
+            .iter()
+            .copied()
+            .filter(|v| !v.is_ascii_whitespace())
+            .collect();
+
+        Self::try_parse(bytes)
+    }
+}
+
+fn try_parse(
+    bytes: impl AsRef<[u8]>,
+    conversion_fn: impl Fn(u8, u8) -> Option<u8>,
+) -> Result<HexString, HexStringError> {
+    let bytes = bytes.as_ref();
+    if bytes.len() % 2 != 0 {
+        return Err(HexStringError::InvalidLength {
+            encountered: bytes.len(),
+        });
+    }
+
+    let mut ret = Vec::with_capacity(bytes.len() / 2);
+    let mut i = 0;
+    let mut j = 1;
+    for _ in 0..ret.capacity() {
+        let a = unsafe { *bytes.get_unchecked(i) };
+        let b = unsafe { *bytes.get_unchecked(j) };
+        conversion_fn(a, b)
+            .ok_or(HexStringError::InvalidByte {
+                msb: a,
+                lsb: b,
+                index: i,
+            })
+            .map(|w| ret.push(w))?;
+
+        // if len == usize::MAX, this will overflow after the last iteration
+        // which is fine
+        i = i.wrapping_add(2);
+        j = j.wrapping_add(2);
+    }
+
+    Ok(HexString::new(ret))
+}
+
+fn try_parse_prefixed(
+    bytes: impl AsRef<[u8]>,
+    conversion_fn: impl Fn(u8, u8) -> Option<u8>,
+) -> Result<HexString, HexStringError> {
+    let [b'0', b'x' | b'X', bytes @ ..] = bytes.as_ref() else {
+        return Err(HexStringError::MissingPrefix);
+    };
+
+    try_parse(bytes, conversion_fn).map_err(|err| match err {
+        HexStringError::InvalidLength { encountered } => HexStringError::InvalidLength {
+            encountered: encountered + 2,
+        },
+        HexStringError::InvalidByte { msb, lsb, index } => HexStringError::InvalidByte {
+            msb,
+            lsb,
+            index: index + 2,
+        },
+        HexStringError::UnexpectedSeparator { position } => HexStringError::UnexpectedSeparator {
+            position: position + 2,
+        },
+        HexStringError::MissingSeparator { position } => HexStringError::MissingSeparator {
+            position: position + 2,
+        },
+        err @ HexStringError::MissingPrefix => err,
+    })
+}
+
+fn push_str(
+    dst: &mut Vec<u8>,
+    bytes: impl AsRef<[u8]>,
+    conversion_fn: impl Fn(u8, u8) -> Option<u8>,
+) -> Result<(), HexStringError> {
+    let bytes = bytes.as_ref();
+    let offset = dst.len() * 2;
+    if bytes.len() % 2 != 0 {
+        return Err(HexStringError::InvalidLength {
+            encountered: offset + bytes.len(),
+        });
+    }
+
+    let len = dst.len();
+    dst.reserve(bytes.len() / 2);
+    for (i, pair) in bytes.chunks_exact(2).enumerate() {
+        let (a, b) = (pair[0], pair[1]);
+        let Some(v) = conversion_fn(a, b) else {
+            dst.truncate(len);
+            return Err(HexStringError::InvalidByte {
+                msb: a,
+                lsb: b,
+                index: offset + i * 2,
+            });
+        };
+
+        dst.push(v);
+    }
+
+    Ok(())
+}
+
+/// Non-empty lines of `input`, trimmed, with their line numbers
+fn lines(input: &str) -> impl Iterator<Item = (usize, &str)> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, v)| (i + 1, v.trim_ascii()))
+        .filter(|(_, v)| !v.is_empty())
+}
+
+#[cfg(feature = "std")]
+fn from_reader(r: &mut impl io::BufRead, skip_whitespace: bool) -> io::Result<HexString> {
+    let mut parser = HexParser::new();
+    loop {
+        let buf = match r.fill_buf() {
+            Ok([]) => break,
+            Ok(v) => v,
+            Err(err) if err.kind() == io::ErrorKind::Interrupted => continue,
+            Err(err) => return Err(err),
+        };
+        let len = buf.len();
+
+        if skip_whitespace {
+            buf.split(u8::is_ascii_whitespace)
+                .try_for_each(|v| parser.feed(v))
+        } else {
+            parser.feed(buf)
+        }
+        .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+
+        r.consume(len);
+    }
+
+    parser
+        .finish()
+        .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))
+}
+
+impl Display for HexString {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if f.width().is_none() && f.precision().is_none() {
+            self.write_lower_to(f)
+        } else {
+            f.pad(&self.to_lower())
+        }
+    }
+}
+
+impl LowerHex for HexString {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.pad_integral(true, "0x", &self.to_lower())
+    }
+}
+
+impl UpperHex for HexString {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.pad_integral(true, "0x", &self.to_upper())
+    }
+}
+
+impl Debug for HexString {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("HexString")
+            .field("inner", &format_args!("\"{self}\""))
+            .finish()
+    }
+}
+
+impl FromStr for HexString {
+    type Err = HexStringError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_parse(s)
+    }
+}
+
+impl From<Vec<u8>> for HexString {
+    fn from(value: Vec<u8>) -> Self {
+        Self::new(value)
+    }
+}
+
+impl From<&[u8]> for HexString {
+    fn from(value: &[u8]) -> Self {
+        Self::new(value)
+    }
+}
+
+impl From<HexString> for Vec<u8> {
+    fn from(value: HexString) -> Self {
+        value.0
+    }
+}
+
+impl From<Box<[u8]>> for HexString {
+    fn from(value: Box<[u8]>) -> Self {
+        Self::new(value.into_vec())
+    }
+}
+
+impl From<HexString> for Box<[u8]> {
+    fn from(value: HexString) -> Self {
+        value.0.into_boxed_slice()
+    }
+}
+
+impl From<HexString> for Arc<[u8]> {
+    fn from(value: HexString) -> Self {
+        value.0.into()
+    }
+}
+
+impl FromIterator<u8> for HexString {
+    fn from_iter<T: IntoIterator<Item = u8>>(iter: T) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
+impl Extend<u8> for HexString {
+    fn extend<T: IntoIterator<Item = u8>>(&mut self, iter: T) {
+        self.0.extend(iter);
+    }
+}
+
+impl<'a> Extend<&'a u8> for HexString {
+    fn extend<T: IntoIterator<Item = &'a u8>>(&mut self, iter: T) {
+        self.0.extend(iter);
+    }
+}
+
+impl<'a> Extend<&'a [u8]> for HexString {
+    fn extend<T: IntoIterator<Item = &'a [u8]>>(&mut self, iter: T) {
+        for v in iter {
+            self.0.extend_from_slice(v);
+        }
+    }
+}
+
+impl<const N: usize> Extend<HexStringN<N>> for HexString {
+    fn extend<T: IntoIterator<Item = HexStringN<N>>>(&mut self, iter: T) {
+        for v in iter {
+            self.push_chunk(&v);
+        }
+    }
+}
+
+impl Extend<HexString> for HexString {
+    fn extend<T: IntoIterator<Item = HexString>>(&mut self, iter: T) {
+        for v in iter {
+            self.0.extend_from_slice(&v);
+        }
+    }
+}
+
+impl TryFrom<&'_ str> for HexString {
+    type Error = HexStringError;
+
+    fn try_from(value: &'_ str) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl TryFrom<String> for HexString {
+    type Error = HexStringError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl Not for HexString {
+    type Output = HexString;
+
+    fn not(mut self) -> Self::Output {
+        self.map_inplace(|v| !v);
+        self
+    }
+}
+
+impl Not for &HexString {
+    type Output = HexString;
+
+    fn not(self) -> Self::Output {
+        self.map(|v| !v)
+    }
+}
+
+impl<const N: usize> PartialEq<[u8; N]> for HexString {
+    fn eq(&self, other: &[u8; N]) -> bool {
+        &*self.0 == other
+    }
+}
+
+impl<const N: usize> PartialEq<&[u8; N]> for HexString {
+    fn eq(&self, other: &&[u8; N]) -> bool {
+        &*self.0 == *other
+    }
+}
+
+impl PartialEq<[u8]> for HexString {
+    fn eq(&self, other: &[u8]) -> bool {
+        self.0 == other
+    }
+}
+
+impl PartialEq<&[u8]> for HexString {
+    fn eq(&self, other: &&[u8]) -> bool {
+        self.0 == *other
+    }
+}
+
+impl PartialEq<str> for HexString {
+    #[allow(clippy::many_single_char_names)]
+    fn eq(&self, other: &str) -> bool {
+        if other.len() % 2 == 0 && other.len() / 2 == self.0.len() {
+            let bytes = other.as_bytes();
+            let mut i = 0;
+            let mut j = 1;
+            for x in &*self.0 {
+                let a = unsafe { *bytes.get_unchecked(i) };
+                let b = unsafe { *bytes.get_unchecked(j) };
+
+                let Some(y) = utils::parse(a, b) else {
+                    return false;
+                };
+
+                if *x != y {
+                    return false;
+                }
+
+                // if len == usize::MAX, this will overflow after the last iteration
+                // which is fine
+                i = i.wrapping_add(2);
+                j = j.wrapping_add(2);
+            }
+
+            true
+        } else {
+            false
+        }
+    }
 }
 
-impl From<Vec<u8>> for HexString {
-    fn from(value: Vec<u8>) -> Self {
-        Self::new(value)
-    }
-}
+impl PartialEq<&str> for HexString {
+    fn eq(&self, other: &&str) -> bool {
+        self == *other
+    }
+}
+
+impl PartialEq<String> for HexString {
+    fn eq(&self, other: &String) -> bool {
+        self == other.as_str()
+    }
+}
+
+impl<const N: usize> PartialEq<HexStringN<N>> for HexString {
+    fn eq(&self, other: &HexStringN<N>) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<const N: usize> PartialOrd<HexStringN<N>> for HexString {
+    fn partial_cmp(&self, other: &HexStringN<N>) -> Option<Ordering> {
+        Some(self.as_slice().cmp(other.as_slice()))
+    }
+}
+
+impl IntoIterator for HexString {
+    type Item = u8;
+    type IntoIter = alloc::vec::IntoIter<u8>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a HexString {
+    type Item = &'a u8;
+    type IntoIter = core::slice::Iter<'a, u8>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a mut HexString {
+    type Item = &'a mut u8;
+    type IntoIter = core::slice::IterMut<'a, u8>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl Deref for HexString {
+    type Target = [u8];
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for HexString {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl AsRef<HexString> for HexString {
+    fn as_ref(&self) -> &HexString {
+        self
+    }
+}
+
+impl AsMut<HexString> for HexString {
+    fn as_mut(&mut self) -> &mut HexString {
+        self
+    }
+}
+
+impl AsRef<[u8]> for HexString {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl AsMut<[u8]> for HexString {
+    fn as_mut(&mut self) -> &mut [u8] {
+        &mut self.0
+    }
+}
+
+impl Borrow<Vec<u8>> for HexString {
+    fn borrow(&self) -> &Vec<u8> {
+        &self.0
+    }
+}
+
+impl BorrowMut<Vec<u8>> for HexString {
+    fn borrow_mut(&mut self) -> &mut Vec<u8> {
+        &mut self.0
+    }
+}
+
+impl Borrow<[u8]> for HexString {
+    fn borrow(&self) -> &[u8] {
+        self
+    }
+}
+
+impl BorrowMut<[u8]> for HexString {
+    fn borrow_mut(&mut self) -> &mut [u8] {
+        self
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for HexString {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct Visitor;
+
+        impl<'de> serde::de::Visitor<'de> for Visitor {
+            type Value = HexString;
+
+            fn expecting(&self, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                formatter.write_str("hex string or bytes")
+            }
+
+            fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                v.parse().map_err(|err| E::custom(err))
+            }
+
+            fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                Ok(HexString::from(v))
+            }
+
+            fn visit_byte_buf<E>(self, v: Vec<u8>) -> Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                Ok(HexString::from(v))
+            }
+        }
+
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(Visitor)
+        } else {
+            deserializer.deserialize_byte_buf(Visitor)
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for HexString {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        if serializer.is_human_readable() {
+            serializer.collect_str(self)
+        } else {
+            serializer.serialize_bytes(&self.0)
+        }
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize::Zeroize for HexString {
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+#[cfg(feature = "bytes")]
+impl From<bytes::Bytes> for HexString {
+    fn from(value: bytes::Bytes) -> Self {
+        Self(value.into())
+    }
+}
+
+#[cfg(feature = "bytes")]
+impl From<HexString> for bytes::Bytes {
+    fn from(value: HexString) -> Self {
+        value.0.into()
+    }
+}
+
+// SAFETY: every method is forwarded to the inner `Vec<u8>`'s implementation
+#[cfg(feature = "bytes")]
+unsafe impl bytes::BufMut for HexString {
+    fn remaining_mut(&self) -> usize {
+        self.0.remaining_mut()
+    }
+
+    unsafe fn advance_mut(&mut self, cnt: usize) {
+        self.0.advance_mut(cnt);
+    }
+
+    fn chunk_mut(&mut self) -> &mut bytes::buf::UninitSlice {
+        self.0.chunk_mut()
+    }
+
+    fn put_slice(&mut self, src: &[u8]) {
+        self.0.put_slice(src);
+    }
+}
+
+#[cfg(feature = "sqlx")]
+impl<DB: sqlx::Database> sqlx::Type<DB> for HexString
+where
+    Vec<u8>: sqlx::Type<DB>,
+{
+    fn type_info() -> DB::TypeInfo {
+        <Vec<u8> as sqlx::Type<DB>>::type_info()
+    }
+
+    fn compatible(ty: &DB::TypeInfo) -> bool {
+        <Vec<u8> as sqlx::Type<DB>>::compatible(ty)
+    }
+}
+
+#[cfg(feature = "sqlx")]
+impl<'q, DB: sqlx::Database> sqlx::Encode<'q, DB> for HexString
+where
+    Vec<u8>: sqlx::Encode<'q, DB>,
+{
+    fn encode_by_ref(
+        &self,
+        buf: &mut DB::ArgumentBuffer<'q>,
+    ) -> Result<sqlx::encode::IsNull, sqlx::error::BoxDynError> {
+        self.0.encode_by_ref(buf)
+    }
+}
+
+#[cfg(feature = "sqlx")]
+impl<'r, DB: sqlx::Database> sqlx::Decode<'r, DB> for HexString
+where
+    Vec<u8>: sqlx::Decode<'r, DB>,
+{
+    fn decode(value: DB::ValueRef<'r>) -> Result<Self, sqlx::error::BoxDynError> {
+        <Vec<u8> as sqlx::Decode<'r, DB>>::decode(value).map(Self)
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for HexString {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let len = u.arbitrary_len::<u8>()?;
+        u.bytes(len).map(|v| Self(v.to_vec()))
+    }
+
+    fn arbitrary_take_rest(u: arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(Self(u.take_rest().to_vec()))
+    }
+
+    fn size_hint(_depth: usize) -> (usize, Option<usize>) {
+        (0, None)
+    }
+}
+
+#[cfg(feature = "proptest")]
+impl proptest::arbitrary::Arbitrary for HexString {
+    type Parameters = ();
+    type Strategy = proptest::strategy::Map<
+        proptest::collection::VecStrategy<proptest::num::u8::Any>,
+        fn(Vec<u8>) -> Self,
+    >;
+
+    fn arbitrary_with((): Self::Parameters) -> Self::Strategy {
+        use proptest::strategy::Strategy;
+
+        proptest::collection::vec(proptest::num::u8::ANY, 0..1024).prop_map(HexString)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "rand")]
+    use rand::seq::SliceRandom;
+
+    use super::{HexString, HexStringError, HexStringN, LengthError};
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_formats() {
+        use serde_test::{assert_de_tokens, assert_tokens, Configure, Token};
+
+        let v = HexString::new([0x1a, 0x2b]);
+
+        assert_tokens(&v.clone().readable(), &[Token::Str("1a2b")]);
+        assert_tokens(&v.clone().compact(), &[Token::Bytes(&[0x1a, 0x2b])]);
+        assert_de_tokens(&v.clone().compact(), &[Token::ByteBuf(&[0x1a, 0x2b])]);
+
+        let json = serde_json::to_string(&v).unwrap();
+        assert_eq!(json, r#""1a2b""#);
+        assert_eq!(serde_json::from_str::<HexString>(&json).unwrap(), v);
+
+        let bin = bincode::serialize(&v).unwrap();
+        assert_eq!(bin, [2, 0, 0, 0, 0, 0, 0, 0, 0x1a, 0x2b]);
+        assert_eq!(bincode::deserialize::<HexString>(&bin).unwrap(), v);
+    }
+
+    #[cfg(feature = "bytes")]
+    #[test]
+    fn bytes() {
+        use bytes::{BufMut, Bytes};
+
+        let v = HexString::new([0x1a, 0x2b]);
+        let b = Bytes::from(v.clone());
+        assert_eq!(b, [0x1a, 0x2b][..]);
+        assert_eq!(HexString::from(b), v);
+
+        let mut v = HexString::with_capacity(4);
+        v.put_slice(&[0x1a, 0x2b]);
+        v.put_u8(0x3c);
+        v.put_u16(0x4d5e);
+        assert_eq!(v, "1a2b3c4d5e");
+        assert_eq!(v.len(), 5);
+        assert!(v.capacity() >= 5);
+    }
+
+    #[cfg(feature = "sqlx")]
+    #[tokio::test]
+    async fn sqlx() {
+        let pool = sqlx::SqlitePool::connect("sqlite::memory:").await.unwrap();
+        sqlx::query("CREATE TABLE t (v BLOB NOT NULL)")
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        for v in [HexString::new([]), HexString::new([0x1a, 0x2b, 0x3c])] {
+            sqlx::query("INSERT INTO t (v) VALUES (?)")
+                .bind(&v)
+                .execute(&pool)
+                .await
+                .unwrap();
+            let (ret,): (HexString,) = sqlx::query_as("SELECT v FROM t WHERE v = ?")
+                .bind(&v)
+                .fetch_one(&pool)
+                .await
+                .unwrap();
+            assert_eq!(ret, v);
+        }
+    }
+
+    #[cfg(feature = "arbitrary")]
+    #[test]
+    fn arbitrary() {
+        use arbitrary::{Arbitrary, Unstructured};
+
+        let data: Vec<u8> = (0..=u8::MAX).collect();
+
+        let mut u = Unstructured::new(&data);
+        let v = HexString::arbitrary(&mut u).unwrap();
+        // the length is taken from the end of the input, the bytes from the
+        // beginning
+        assert!(data.starts_with(&v));
+        assert!(v.len() + u.len() < data.len());
+
+        let v = HexString::arbitrary_take_rest(Unstructured::new(&data)).unwrap();
+        assert_eq!(v, data[..]);
+
+        let mut u = Unstructured::new(&[]);
+        assert_eq!(HexString::arbitrary(&mut u).unwrap(), "");
+    }
+
+    #[cfg(feature = "proptest")]
+    proptest::proptest! {
+        #[test]
+        fn proptest_round_trip(v: HexString) {
+            proptest::prop_assert!(v.len() < 1024);
+            proptest::prop_assert_eq!(HexString::try_parse(v.to_lower()).unwrap(), v.clone());
+            proptest::prop_assert_eq!(HexString::try_parse(v.to_upper()).unwrap(), v);
+        }
+    }
+
+    #[cfg(feature = "proptest")]
+    #[test]
+    fn proptest_shrink() {
+        use proptest::{
+            arbitrary::any,
+            strategy::{Strategy, ValueTree},
+            test_runner::TestRunner,
+        };
+
+        let mut runner = TestRunner::deterministic();
+        let mut tree = any::<HexString>().new_tree(&mut runner).unwrap();
+        while tree.simplify() {}
+
+        assert_eq!(tree.current(), "");
+    }
+
+    #[cfg(feature = "zeroize")]
+    #[test]
+    fn zeroize() {
+        use zeroize::{Zeroize, Zeroizing};
+
+        let mut v = HexString::new([0xab; 32]);
+        v.zeroize();
+        assert!(v.is_empty());
+
+        let mut v = Zeroizing::new(HexString::new([0x1a, 0x2b]));
+        v.push(0x3c);
+        assert_eq!(*v, "1a2b3c");
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn big_hex() {
+        let mut rng = rand::thread_rng();
+        let v = (0..262_146).fold(String::new(), |mut acc, _| {
+            let v = *b"0123456789abcdefABCDEF".choose(&mut rng).unwrap();
+            acc.push(v.into());
+            acc
+        });
+
+        // 128 * 1024 + 1
+        let parsed: HexString = v.parse().unwrap();
+        assert_eq!(parsed.to_lower(), v.to_lowercase());
+        assert_eq!(parsed.len(), 131_073);
+    }
+
+    #[test]
+    fn ord() {
+        let mut v = vec![
+            HexString::new([0x1a, 0x2b]),
+            HexString::new([0x1a]),
+            HexString::new([]),
+            HexString::new([0x0f, 0xff, 0xff]),
+        ];
+        v.sort();
+
+        assert_eq!(v, ["", "0fffff", "1a", "1a2b"]);
+        for w in v.windows(2) {
+            assert_eq!(w[0].cmp(&w[1]), w[0].as_slice().cmp(w[1].as_slice()));
+        }
+    }
+
+    #[test]
+    fn ord_hex_string_n() {
+        let a = HexString::new([0x1a]);
+        let b = HexStringN::new([0x1a, 0x2b]);
+
+        assert!(a < b);
+        assert!(b > a);
+        assert_eq!(a.partial_cmp(&b), Some(a.as_slice().cmp(b.as_slice())));
+        assert_eq!(HexString::new([0x1a, 0x2b]), b);
+    }
+
+    #[test]
+    fn default() {
+        assert!(HexString::default().is_empty());
+        assert_eq!(HexString::default(), "");
+    }
+
+    #[test]
+    fn index() {
+        let mut v = HexString::new([0x1a, 0x2b, 0x3c, 0x4d]);
+
+        assert_eq!(v[1], 0x2b);
+        assert_eq!(v[1..3], [0x2b, 0x3c]);
+        assert_eq!(v[..], [0x1a, 0x2b, 0x3c, 0x4d]);
+        assert_eq!(v[2..], [0x3c, 0x4d]);
+        assert_eq!(v[..2], [0x1a, 0x2b]);
+        assert_eq!(v[1..=2], [0x2b, 0x3c]);
+        assert_eq!(v[..=1], [0x1a, 0x2b]);
+
+        v[3] = 0xaa;
+        v[..2].copy_from_slice(&[0x00, 0x11]);
+        assert_eq!(v, "00113caa");
+    }
+
+    #[test]
+    fn collection() {
+        let mut v = HexString::default();
+        v.push(0x1a);
+        assert_eq!(v.pop(), Some(0x1a));
+        assert_eq!(v.pop(), None);
+
+        let mut v: HexString = "0102030405060708".parse().unwrap();
+        v.truncate(10);
+        assert_eq!(v.len(), 8);
+        v.truncate(6);
+        assert_eq!(v, "010203040506");
+        v.truncate(0);
+        assert!(v.is_empty());
+
+        let mut v: HexString = "0102030405060708".parse().unwrap();
+        assert_eq!(v.drain(..2).collect::<Vec<_>>(), [0x01, 0x02]);
+        assert_eq!(v.drain(4..).collect::<Vec<_>>(), [0x07, 0x08]);
+        assert_eq!(v, "03040506");
+
+        v.retain(|v| v > 0x04);
+        assert_eq!(v, "0506");
+        v.retain(|_| false);
+        assert!(v.is_empty());
 
-impl From<&[u8]> for HexString {
-    fn from(value: &[u8]) -> Self {
-        Self::new(value)
+        let mut v: HexString = "0102".parse().unwrap();
+        v.clear();
+        assert_eq!(v, "");
     }
-}
 
-impl From<HexString> for Vec<u8> {
-    fn from(value: HexString) -> Self {
-        value.0
+    #[test]
+    fn capacity() {
+        let mut v = HexString::with_capacity(100);
+        assert!(v.capacity() >= 100);
+
+        for i in 0..50 {
+            v.push(i);
+        }
+        assert!(v.capacity() >= 100);
+
+        v.shrink_to_fit();
+        assert_eq!(v.capacity(), v.len());
+
+        v.reserve(50);
+        assert!(v.capacity() >= 100);
     }
-}
 
-impl TryFrom<&'_ str> for HexString {
-    type Error = HexStringError;
+    #[test]
+    fn try_into_array() {
+        let v = HexString::new([0x1a, 0x2b, 0x3c]);
 
-    fn try_from(value: &'_ str) -> Result<Self, Self::Error> {
-        value.parse()
+        assert_eq!(v.clone().try_into_array::<2>().unwrap_err(), v);
+        assert_eq!(v.clone().try_into_array::<4>().unwrap_err(), v);
+        let array = v.clone().try_into_array::<3>().unwrap();
+        assert_eq!(array, [0x1a, 0x2b, 0x3c]);
+        assert_eq!(array.to_hex_string(), v);
+
+        assert_eq!(v.try_as_array::<2>(), None);
+        assert_eq!(v.try_as_array::<4>(), None);
+        let array = v.try_as_array::<3>().unwrap();
+        assert_eq!(array.as_ptr(), v.as_slice().as_ptr());
     }
-}
 
-impl TryFrom<String> for HexString {
-    type Error = HexStringError;
+    #[test]
+    fn push_str() {
+        let mut v = HexString::default();
+        v.push_str("1a").unwrap();
+        v.push_str("").unwrap();
+        v.push_str("2B3c").unwrap();
+        assert_eq!(v, "1a2b3c");
 
-    fn try_from(value: String) -> Result<Self, Self::Error> {
-        value.parse()
+        assert_eq!(
+            v.push_str("4dzz").unwrap_err(),
+            HexStringError::InvalidByte {
+                msb: b'z',
+                lsb: b'z',
+                index: 8
+            }
+        );
+        assert_eq!(
+            v.push_str("4d5").unwrap_err(),
+            HexStringError::InvalidLength { encountered: 9 }
+        );
+        assert_eq!(v, "1a2b3c");
     }
-}
 
-impl<const N: usize> PartialEq<[u8; N]> for HexString {
-    fn eq(&self, other: &[u8; N]) -> bool {
-        &*self.0 == other
+    #[test]
+    fn try_reserve() {
+        let mut v = HexString::new([0x1a]);
+        v.try_reserve(64).unwrap();
+        assert!(v.capacity() >= 65);
+        v.try_reserve_exact(128).unwrap();
+        assert!(v.capacity() >= 129);
+
+        // capacity overflow, reported instead of aborting
+        assert!(v.try_reserve(usize::MAX).is_err());
+        assert!(v.try_reserve_exact(isize::MAX as usize).is_err());
+        assert_eq!(v, "1a");
     }
-}
 
-impl<const N: usize> PartialEq<&[u8; N]> for HexString {
-    fn eq(&self, other: &&[u8; N]) -> bool {
-        &*self.0 == *other
+    #[test]
+    fn push_pop_chunk() {
+        let mut v = HexString::default();
+        assert_eq!(v.pop_chunk::<1>(), None);
+        assert_eq!(v.pop_chunk::<0>(), Some(HexStringN::new([])));
+
+        v.push_chunk(&HexStringN::new([0x1a, 0x2b]));
+        v.push_chunk(&HexStringN::new([0x3c, 0x4d, 0x5e, 0x6f]));
+        assert_eq!(v, "1a2b3c4d5e6f");
+
+        assert_eq!(v.pop_chunk::<4>().unwrap(), [0x3c, 0x4d, 0x5e, 0x6f]);
+        assert_eq!(v.pop_chunk::<4>(), None);
+        assert_eq!(v, "1a2b");
+
+        v.push_chunk(&HexStringN::new([0x70]));
+        assert_eq!(v.pop_chunk::<1>().unwrap(), [0x70]);
+        assert_eq!(v.pop_chunk::<2>().unwrap(), [0x1a, 0x2b]);
+        assert!(v.is_empty());
     }
-}
 
-impl PartialEq<[u8]> for HexString {
-    fn eq(&self, other: &[u8]) -> bool {
-        self.0 == other
+    #[test]
+    fn map() {
+        let v = HexString::new([0x00, 0x1a, 0xff]);
+        assert_eq!(v.map(|v| v ^ 0xaa), "aab055");
+        assert_eq!(v.map(|v| v), v);
+        assert_eq!(HexString::default().map(|v| v ^ 0xaa), "");
+
+        let mut w = v.clone();
+        w.map_inplace(|v| v ^ 0xaa);
+        assert_eq!(w, v.map(|v| v ^ 0xaa));
+        assert_eq!(w.len(), v.len());
     }
-}
 
-impl PartialEq<&[u8]> for HexString {
-    fn eq(&self, other: &&[u8]) -> bool {
-        self.0 == *other
+    #[test]
+    fn lower_upper_hex() {
+        let v = HexString::new([0x1a, 0x2b]);
+        assert_eq!(format!("{v:x}"), "1a2b");
+        assert_eq!(format!("{v:X}"), "1A2B");
+        assert_eq!(format!("{v:#x}"), "0x1a2b");
+        assert_eq!(format!("{v:#X}"), "0x1A2B");
+        assert_eq!(format!("{v:08x}"), "00001a2b");
+        assert_eq!(format!("{v:#08X}"), "0x001A2B");
+        assert_eq!(format!("{v:>6x}"), "  1a2b");
+        assert_eq!(format!("{v}"), format!("{v:x}"));
+        assert_ne!(format!("{v:?}"), format!("{v}"));
     }
-}
 
-impl PartialEq<str> for HexString {
-    #[allow(clippy::many_single_char_names)]
-    fn eq(&self, other: &str) -> bool {
-        if other.len() % 2 == 0 && other.len() / 2 == self.0.len() {
-            let bytes = other.as_bytes();
-            let mut i = 0;
-            let mut j = 1;
-            for x in &*self.0 {
-                let a = unsafe { *bytes.get_unchecked(i) };
-                let b = unsafe { *bytes.get_unchecked(j) };
+    #[test]
+    fn boxed_slice_conversions() {
+        let boxed: Box<[u8]> = Box::new([0x1a, 0x2b]);
+        let ptr = boxed.as_ptr();
 
-                let Some(y) = utils::parse(a, b) else {
-                    return false;
-                };
+        let v = HexString::from(boxed);
+        assert_eq!(v, "1a2b");
+        assert_eq!(v.as_ptr(), ptr);
 
-                if *x != y {
-                    return false;
-                }
+        let boxed = Box::<[u8]>::from(v.clone());
+        assert_eq!(*boxed, [0x1a, 0x2b]);
 
-                // if len == usize::MAX, this will overflow after the last iteration
-                // which is fine
-                i = i.wrapping_add(2);
-                j = j.wrapping_add(2);
-            }
+        let shared = std::sync::Arc::<[u8]>::from(v);
+        let handle = {
+            let shared = shared.clone();
+            std::thread::spawn(move || shared.len())
+        };
+        assert_eq!(handle.join().unwrap(), 2);
+        assert_eq!(*shared, [0x1a, 0x2b]);
+    }
 
-            true
-        } else {
-            false
+    #[test]
+    fn iter_hex_pairs() {
+        let v = HexString::new((0..=u8::MAX).collect::<Vec<_>>());
+        let pairs: Vec<_> = v.iter_hex_pairs().collect();
+        assert_eq!(pairs.len(), v.len());
+
+        for (&v, &(msb, lsb)) in v.iter().zip(&pairs) {
+            assert!(msb <= 0x0f && lsb <= 0x0f);
+            assert_eq!((msb << 4) | lsb, v);
         }
+
+        assert_eq!(HexString::default().iter_hex_pairs().len(), 0);
     }
-}
 
-impl PartialEq<&str> for HexString {
-    fn eq(&self, other: &&str) -> bool {
-        self == *other
+    #[test]
+    fn try_chunks_as() {
+        let mut v = HexString::new((0..12).collect::<Vec<u8>>());
+
+        let chunks = v.try_chunks_as::<4>().unwrap();
+        assert_eq!(chunks.len(), 3);
+        assert_eq!(chunks[1], [4, 5, 6, 7]);
+        assert_eq!(v.try_chunks_as::<5>(), None);
+        assert_eq!(v.try_chunks_as::<12>().unwrap().len(), 1);
+        assert_eq!(HexString::default().try_chunks_as::<3>().unwrap().len(), 0);
+
+        v.try_chunks_as_mut::<6>().unwrap()[1] = [0xff; 6];
+        assert_eq!(v, "000102030405ffffffffffff");
     }
-}
 
-impl PartialEq<String> for HexString {
-    fn eq(&self, other: &String) -> bool {
-        self == other.as_str()
+    #[test]
+    fn zip_with() {
+        let v = HexString::new([0x00, 0x1a, 0xff]);
+        let key = [0xaa, 0xaa, 0xaa];
+
+        let xored = v.zip_with(&key, |a, b| a ^ b).unwrap();
+        assert_eq!(xored, "aab055");
+        assert_eq!(xored.zip_with(&key, |a, b| a ^ b).unwrap(), v);
+        assert_eq!(v.zip_with(&[0x0f; 3], |a, b| a & b).unwrap(), "000a0f");
+        assert_eq!(v.zip_with(&key, |a, _| a).unwrap(), v);
+
+        let mut w = v.clone();
+        w.zip_with_inplace(&key, |a, b| a ^ b).unwrap();
+        assert_eq!(w, xored);
+
+        assert_eq!(
+            w.zip_with_inplace(&[0x00; 4], |a, b| a ^ b),
+            Err(LengthError {
+                expected: 3,
+                encountered: 4
+            })
+        );
+        assert_eq!(w, xored);
     }
-}
 
-impl Deref for HexString {
-    type Target = Vec<u8>;
+    #[test]
+    fn try_parse_collect_errors() {
+        assert_eq!(HexString::try_parse_collect_errors("").unwrap(), "");
+        assert_eq!(
+            HexString::try_parse_collect_errors("1a2B3c").unwrap(),
+            "1a2b3c"
+        );
+        assert_eq!(
+            HexString::try_parse_collect_errors("1a2").unwrap_err(),
+            [HexStringError::InvalidLength { encountered: 3 }]
+        );
 
-    fn deref(&self) -> &Self::Target {
-        &self.0
+        let errors = HexString::try_parse_collect_errors("x01a2b3cy14d").unwrap_err();
+        assert_eq!(
+            errors,
+            [
+                HexStringError::InvalidByte {
+                    msb: b'x',
+                    lsb: b'0',
+                    index: 0
+                },
+                HexStringError::InvalidByte {
+                    msb: b'y',
+                    lsb: b'1',
+                    index: 8
+                },
+            ]
+        );
     }
-}
 
-impl DerefMut for HexString {
-    fn deref_mut(&mut self) -> &mut Self::Target {
-        &mut self.0
+    #[test]
+    fn try_parse_separated() {
+        assert_eq!(HexString::try_parse_separated("", b':').unwrap(), "");
+        assert_eq!(HexString::try_parse_separated("1A", b':').unwrap(), "1a");
+        assert_eq!(
+            HexString::try_parse_separated("1a-2b-3c-4d", b'-').unwrap(),
+            "1a2b3c4d"
+        );
+
+        assert_eq!(
+            HexString::try_parse_separated("1a--2b", b'-'),
+            Err(HexStringError::UnexpectedSeparator { position: 3 })
+        );
+        assert_eq!(
+            HexString::try_parse_separated("-1a-2b", b'-'),
+            Err(HexStringError::UnexpectedSeparator { position: 0 })
+        );
+        assert_eq!(
+            HexString::try_parse_separated("1a:2b", b'-'),
+            Err(HexStringError::MissingSeparator { position: 2 })
+        );
+        assert_eq!(
+            HexString::try_parse_separated("1a-2g", b'-'),
+            Err(HexStringError::InvalidByte {
+                msb: b'2',
+                lsb: b'g',
+                index: 3
+            })
+        );
+        assert_eq!(
+            HexString::try_parse_separated("1a-2", b'-'),
+            Err(HexStringError::InvalidLength { encountered: 4 })
+        );
+        assert_eq!(
+            HexString::try_parse_separated("67e55044-10b1-426f-9247-bb680e5fe0c8", b'-'),
+            Err(HexStringError::MissingSeparator { position: 2 })
+        );
     }
-}
 
-impl AsRef<HexString> for HexString {
-    fn as_ref(&self) -> &HexString {
-        self
+    #[test]
+    fn format_separated() {
+        let v = HexString::new([0x1a, 0x2b, 0x3c, 0x4d, 0x5e]);
+
+        assert_eq!(v.format_separated(':', 1), "1a:2b:3c:4d:5e");
+        assert_eq!(v.format_separated('-', 2), "1a2b-3c4d-5e");
+        assert_eq!(v.format_separated(':', 5), v.to_lower());
+        assert_eq!(v.format_separated(':', 6), v.to_lower());
+        assert_eq!(HexString::new([]).format_separated(':', 1), "");
+
+        for group_size in 1..=6 {
+            let mut s = String::new();
+            v.write_separated_to(&mut s, ' ', group_size).unwrap();
+            assert_eq!(s, v.format_separated(' ', group_size));
+        }
     }
-}
 
-impl AsMut<HexString> for HexString {
-    fn as_mut(&mut self) -> &mut HexString {
-        self
+    #[test]
+    #[should_panic = "group_size must be non-zero"]
+    fn format_separated_zero_group_size() {
+        let _ = HexString::new([0x1a]).format_separated(':', 0);
     }
-}
 
-impl AsRef<[u8]> for HexString {
-    fn as_ref(&self) -> &[u8] {
-        &self.0
+    #[test]
+    fn display_padding() {
+        let v = HexString::new([0x1a, 0x2b]);
+
+        assert_eq!(format!("{v:<8}"), "1a2b    ");
+        assert_eq!(format!("{v:>8}"), "    1a2b");
+        assert_eq!(format!("{v:^8}"), "  1a2b  ");
+        assert_eq!(format!("{v:*>8}"), "****1a2b");
+        assert_eq!(format!("{v:-^9}"), "--1a2b---");
+        assert_eq!(format!("{v:>2}"), "1a2b");
+        assert_eq!(format!("{v:.2}"), "1a");
+        assert_eq!(format!("{v}"), "1a2b");
     }
-}
 
-impl AsMut<[u8]> for HexString {
-    fn as_mut(&mut self) -> &mut [u8] {
-        &mut self.0
+    #[cfg(feature = "getrandom")]
+    #[test]
+    fn try_random() {
+        assert_eq!(HexString::try_random(0).unwrap(), "");
+        assert_eq!(HexString::try_random(1024).unwrap().len(), 1024);
+
+        // probability of a collision is 2^-128
+        let a = HexString::try_random(16).unwrap();
+        let b = HexString::try_random(16).unwrap();
+        assert_ne!(a, b);
     }
-}
 
-impl Borrow<Vec<u8>> for HexString {
-    fn borrow(&self) -> &Vec<u8> {
-        self
+    #[test]
+    fn sort() {
+        use core::cmp::Reverse;
+
+        // sorting is provided by `DerefMut<Target = [u8]>`
+        let mut v = HexString::new([0x3c, 0x1a, 0xff, 0x00, 0x2b]);
+        let mut stable = v.clone();
+        #[allow(clippy::stable_sort_primitive)]
+        stable.sort();
+        assert_eq!(stable, "001a2b3cff");
+
+        v.sort_unstable();
+        assert_eq!(v, stable);
+        v.sort_unstable();
+        assert_eq!(v, "001a2b3cff");
+
+        v.sort_by_key(|v| Reverse(*v));
+        assert_eq!(v, "ff3c2b1a00");
+
+        v.sort_unstable();
+        assert_eq!(v, "001a2b3cff");
+
+        v.sort_by(|a, b| a.count_ones().cmp(&b.count_ones()).then(a.cmp(b)));
+        assert_eq!(v, "001a2b3cff");
     }
-}
 
-impl BorrowMut<Vec<u8>> for HexString {
-    fn borrow_mut(&mut self) -> &mut Vec<u8> {
-        self
+    #[test]
+    fn insert_remove() {
+        let mut v = HexString::new([0x1a, 0x2b]);
+
+        v.insert(0, 0x00);
+        v.insert(3, 0xff);
+        assert_eq!(v, "001a2bff");
+        assert_eq!(v.len(), 4);
+
+        assert_eq!(v.remove(3), 0xff);
+        assert_eq!(v.remove(0), 0x00);
+        assert_eq!(v, "1a2b");
+        assert_eq!(v.len(), 2);
+
+        v.swap(0, 1);
+        assert_eq!(v, "2b1a");
+
+        let original = HexString::new([0x1a, 0x2b, 0x3c]);
+        let mut v = original.clone();
+        v.reverse();
+        assert_eq!(v, original.reversed());
+        v.reverse();
+        assert_eq!(v, original);
+        assert_eq!(original.reversed().reversed(), original);
     }
-}
 
-impl Borrow<[u8]> for HexString {
-    fn borrow(&self) -> &[u8] {
-        self
+    #[test]
+    #[should_panic = "insertion index"]
+    fn insert_out_of_bounds() {
+        HexString::new([0x1a]).insert(2, 0x2b);
     }
-}
 
-impl BorrowMut<[u8]> for HexString {
-    fn borrow_mut(&mut self) -> &mut [u8] {
-        self
+    #[test]
+    #[should_panic = "removal index"]
+    fn remove_out_of_bounds() {
+        HexString::new([0x1a]).remove(1);
     }
-}
 
-#[cfg(feature = "serde")]
-impl<'de> serde::Deserialize<'de> for HexString {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
-    where
-        D: serde::Deserializer<'de>,
-    {
-        struct Visitor;
+    #[test]
+    fn extend() {
+        let mut v = HexString::new([0x1a]);
 
-        impl<'de> serde::de::Visitor<'de> for Visitor {
-            type Value = HexString;
+        v.extend(vec![0xaa, 0xbb]);
+        assert_eq!(v, "1aaabb");
+        assert_eq!(v.len(), 3);
 
-            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("hex string")
-            }
+        v.extend(core::iter::empty::<u8>());
+        assert_eq!(v, "1aaabb");
 
-            fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
-            where
-                E: serde::de::Error,
-            {
-                v.parse().map_err(|err| E::custom(err))
-            }
+        v.extend(&[0x01, 0x02]);
+        v.extend([&[0x03][..], &[], &[0x04, 0x05]]);
+        assert_eq!(v, "1aaabb0102030405");
+
+        let mut v = HexString::new([]);
+        v.extend([HexStringN::new([0x1a, 0x2b]), HexStringN::new([0x3c, 0x4d])]);
+        v.extend([
+            HexString::new([0x5e]),
+            HexString::new([]),
+            HexString::new([0x6f]),
+        ]);
+        assert_eq!(v, "1a2b3c4d5e6f");
+
+        let v: HexString = (0..4).collect();
+        assert_eq!(v, "00010203");
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn from_reader() {
+        use std::io::{BufReader, Cursor, ErrorKind};
+
+        let text = b"1a2b 3c4d\n\t5e6f\r\n7A8B\n\n";
+        let concatenated: Vec<u8> = text
+            .iter()
+            .copied()
+            .filter(|v| !v.is_ascii_whitespace())
+            .collect();
+
+        // a tiny buffer, so pairs and whitespace are split across reads
+        for capacity in [1, 2, 3, 64] {
+            let mut r = BufReader::with_capacity(capacity, Cursor::new(&text[..]));
+            let v = HexString::from_reader(&mut r).unwrap();
+            assert_eq!(v, HexString::try_parse(&concatenated).unwrap());
+            assert_eq!(v, HexString::try_parse_lax(text).unwrap());
+            assert_eq!(v, "1a2b3c4d5e6f7a8b");
         }
 
-        deserializer.deserialize_str(Visitor)
+        let mut r = BufReader::new(Cursor::new(&b""[..]));
+        assert_eq!(HexString::from_reader(&mut r).unwrap(), "");
+        let mut r = BufReader::new(Cursor::new(&b""[..]));
+        assert_eq!(HexString::from_reader_exact(&mut r).unwrap(), "");
+
+        let mut r = BufReader::new(Cursor::new(&b"1a 2b\n3g"[..]));
+        let err = HexString::from_reader(&mut r).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidData);
+        assert_eq!(
+            err.into_inner().unwrap().downcast_ref::<HexStringError>(),
+            Some(&HexStringError::InvalidByte {
+                msb: b'3',
+                lsb: b'g',
+                index: 4
+            })
+        );
+
+        let mut r = BufReader::new(Cursor::new(&b"1a 2b 3"[..]));
+        let err = HexString::from_reader(&mut r).unwrap_err();
+        assert_eq!(
+            err.into_inner().unwrap().downcast_ref::<HexStringError>(),
+            Some(&HexStringError::InvalidLength { encountered: 5 })
+        );
+
+        let mut r = BufReader::new(Cursor::new(&b"1a2b\n"[..]));
+        let err = HexString::from_reader_exact(&mut r).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidData);
     }
-}
 
-#[cfg(feature = "serde")]
-impl serde::Serialize for HexString {
-    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-    where
-        S: serde::Serializer,
-    {
-        self.to_string().serialize(serializer)
+    #[test]
+    fn try_parse_lines() {
+        let input = "1a2b\n\n  3c4d \n \t \nzz\n5E6F\r\n1a2";
+
+        let (parsed, errors) = HexString::try_parse_lines(input);
+        assert_eq!(parsed, ["1a2b", "3c4d", "5e6f"]);
+        assert_eq!(
+            errors,
+            [
+                (
+                    5,
+                    HexStringError::InvalidByte {
+                        msb: b'z',
+                        lsb: b'z',
+                        index: 0
+                    }
+                ),
+                (7, HexStringError::InvalidLength { encountered: 3 })
+            ]
+        );
+
+        assert_eq!(
+            HexString::try_parse_lines_strict(input),
+            Err((
+                5,
+                HexStringError::InvalidByte {
+                    msb: b'z',
+                    lsb: b'z',
+                    index: 0
+                }
+            ))
+        );
+        assert_eq!(
+            HexString::try_parse_lines_strict("1a2b\n\n  3c4d \n").unwrap(),
+            ["1a2b", "3c4d"]
+        );
+
+        assert_eq!(HexString::try_parse_lines(""), (vec![], vec![]));
+        assert_eq!(HexString::try_parse_lines_strict(" \n\n"), Ok(vec![]));
     }
-}
 
-#[cfg(all(test, feature = "rand"))]
-mod tests {
-    use rand::seq::SliceRandom;
+    #[test]
+    fn try_parse_lax() {
+        assert_eq!(
+            HexString::try_parse_lax("1a 2b\n3c 4d").unwrap(),
+            "1a2b3c4d"
+        );
+        assert_eq!(HexString::try_parse_lax("  1a2b  ").unwrap(), "1a2b");
+        assert_eq!(HexString::try_parse_lax(" \n\t").unwrap(), "");
 
-    use super::HexString;
+        let dump = "de ad be ef\n01 23 45 67\r\n89 ab cd ef\n";
+        assert_eq!(
+            HexString::try_parse_lax(dump).unwrap(),
+            "deadbeef0123456789abcdef"
+        );
+
+        // positions are in the input with whitespace removed
+        assert_eq!(
+            HexString::try_parse_lax("1a 2b\n3c 4g"),
+            Err(HexStringError::InvalidByte {
+                msb: b'4',
+                lsb: b'g',
+                index: 6
+            })
+        );
+        assert_eq!(
+            HexString::try_parse_lax("1a 2b 3"),
+            Err(HexStringError::InvalidLength { encountered: 5 })
+        );
+    }
 
     #[test]
-    fn big_hex() {
-        let mut rng = rand::thread_rng();
-        let v = (0..262_146).fold(String::new(), |mut acc, _| {
-            let v = *b"0123456789abcdefABCDEF".choose(&mut rng).unwrap();
-            acc.push(v.into());
-            acc
+    fn encode_to_buf() {
+        let v: HexString = (0..=u8::MAX).collect();
+
+        let mut buf = [0; 512];
+        assert_eq!(v.encode_lower_to_buf(&mut buf), Ok(512));
+        assert_eq!(core::str::from_utf8(&buf).unwrap(), v.to_lower());
+        assert_eq!(buf, v.to_lower().as_bytes());
+
+        assert_eq!(v.encode_upper_to_buf(&mut buf), Ok(512));
+        assert_eq!(core::str::from_utf8(&buf).unwrap(), v.to_upper());
+
+        let mut buf = [b'-'; 6];
+        let n = HexString::new([0xab, 0x01])
+            .encode_lower_to_buf(&mut buf)
+            .unwrap();
+        assert_eq!(&buf[..n], b"ab01");
+        assert_eq!(&buf[n..], b"--");
+
+        assert_eq!(
+            v.encode_lower_to_buf(&mut [0; 511]),
+            Err(LengthError {
+                expected: 512,
+                encountered: 511
+            })
+        );
+        assert_eq!(HexString::new([]).encode_upper_to_buf(&mut []), Ok(0));
+    }
+
+    #[test]
+    fn write_to() {
+        use core::fmt::Write;
+
+        let v = (0..=u8::MAX).collect::<HexString>();
+
+        let mut s = String::from("0x");
+        v.write_lower_to(&mut s).unwrap();
+        assert_eq!(s, format!("0x{}", v.to_lower()));
+
+        let mut s = String::new();
+        v.write_upper_to(&mut s).unwrap();
+        s.write_char('!').unwrap();
+        assert_eq!(s, format!("{}!", v.to_upper()));
+    }
+
+    #[test]
+    fn iter_nibbles() {
+        let v = HexString::new([0xab, 0xcd]);
+        assert_eq!(
+            v.iter_nibbles().collect::<Vec<_>>(),
+            [0x0a, 0x0b, 0x0c, 0x0d]
+        );
+        assert_eq!(
+            v.iter_nibbles().rev().collect::<Vec<_>>(),
+            [0x0d, 0x0c, 0x0b, 0x0a]
+        );
+
+        let v: HexString = (0..=u8::MAX).collect();
+        assert_eq!(v.iter_nibbles().count(), 512);
+        assert!(v.iter_nibbles().all(|n| n <= 15));
+        assert!(v
+            .iter_nibbles()
+            .zip(v.to_lower().chars())
+            .all(|(n, c)| c.to_digit(16) == Some(u32::from(n))));
+
+        assert_eq!(HexString::new([]).iter_nibbles().next(), None);
+    }
+
+    #[test]
+    fn chunks_exact_as() {
+        let v: HexString = (0..10).collect();
+
+        let chunks = v.chunks_exact_as::<3>();
+        assert_eq!(chunks.len(), 3);
+        assert_eq!(chunks, [[0, 1, 2], [3, 4, 5], [6, 7, 8]]);
+        assert_eq!(chunks.as_ptr().cast(), v.as_ptr());
+        assert_eq!(v.try_chunks_as::<3>(), None);
+
+        assert_eq!(v.chunks_exact_as::<5>(), v.try_chunks_as::<5>().unwrap());
+        assert_eq!(v.chunks_exact_as::<5>().len(), v.len() / 5);
+        assert!(v.chunks_exact_as::<11>().is_empty());
+        assert!(HexString::new([]).chunks_exact_as::<1>().is_empty());
+    }
+
+    #[test]
+    fn bit_ops() {
+        let a: HexString = "1a2b3c4d".parse().unwrap();
+
+        assert_eq!(a.xor(&a).unwrap(), [0; 4]);
+        assert_eq!(a.or(&[0xff; 4]).unwrap(), [0xff; 4]);
+        assert_eq!(a.and(&[0xff; 4]).unwrap(), a);
+        assert_eq!(a.and(&[0; 4]).unwrap(), [0; 4]);
+        assert_eq!(a.xor(&!&a).unwrap(), [0xff; 4]);
+        assert_eq!(!!a.clone(), a);
+        assert_eq!(!HexString::new([0x0f, 0xf0]), "f00f");
+        assert_eq!(a, "1a2b3c4d");
+
+        let expected = Err(LengthError {
+            expected: 4,
+            encountered: 3,
         });
+        assert_eq!(a.xor(&[0; 3]), expected);
+        assert_eq!(a.and(&[0; 3]), expected);
+        assert_eq!(a.or(&[0; 3]), expected);
+    }
 
-        // 128 * 1024 + 1
-        let parsed: HexString = v.parse().unwrap();
-        assert_eq!(parsed.to_lower(), v.to_lowercase());
-        assert_eq!(parsed.len(), 131_073);
+    #[test]
+    fn iter() {
+        let mut v = HexString::new([0x01, 0x02, 0x03]);
+        assert_eq!(v.iter().map(|&v| u32::from(v)).sum::<u32>(), 6);
+
+        v.iter_mut().for_each(|b| *b ^= 0xaa);
+        assert_eq!(v, "aba8a9");
+
+        for b in &mut v {
+            *b ^= 0xaa;
+        }
+        assert_eq!(v, "010203");
+        assert!((&v).into_iter().eq(v.iter()));
+
+        let mut bytes = Vec::new();
+        for b in v {
+            bytes.push(b);
+        }
+        assert_eq!(bytes, [0x01, 0x02, 0x03]);
     }
 }
@@ -1,13 +1,16 @@
-use std::{
+use core::{
     borrow::{Borrow, BorrowMut},
-    fmt::{Debug, Display},
+    cmp::Ordering,
+    fmt::{Debug, Display, LowerHex, UpperHex},
     mem::MaybeUninit,
-    ops::{Deref, DerefMut},
+    ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Deref, DerefMut, Not},
     ptr,
     str::FromStr,
 };
 
-use crate::{utils, HexStringNError};
+use alloc::{boxed::Box, string::String, vec, vec::Vec};
+
+use crate::{utils, HexString, HexStringNError, LengthError};
 
 /// A hex string of constant length
 ///
@@ -28,7 +31,7 @@ use crate::{utils, HexStringNError};
 /// assert_eq!(a, b);
 /// ```
 #[repr(transparent)]
-#[derive(Clone, PartialEq, Eq, Hash)]
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct HexStringN<const N: usize>(Box<[u8; N]>);
 
 impl<const N: usize> HexStringN<N> {
@@ -47,6 +50,58 @@ impl<const N: usize> HexStringN<N> {
         Self(v.into())
     }
 
+    /// Create a new `HexStringN` with all bytes set to `0x00`.
+    ///
+    /// # Example:
+    /// ```
+    /// use hex_str::HexStringN;
+    ///
+    /// let v = HexStringN::<4>::zeroed();
+    /// assert_eq!(v, "00000000");
+    /// ```
+    #[must_use]
+    pub fn zeroed() -> Self {
+        Self::filled(0x00)
+    }
+
+    /// Create a new `HexStringN` with all bytes set to `0xff`.
+    ///
+    /// # Example:
+    /// ```
+    /// use hex_str::HexStringN;
+    ///
+    /// let v = HexStringN::<4>::ones();
+    /// assert_eq!(v, "ffffffff");
+    /// ```
+    #[must_use]
+    pub fn ones() -> Self {
+        Self::filled(0xff)
+    }
+
+    /// Create a new `HexStringN` with all bytes set to `byte`.
+    ///
+    /// The array is filled in place on the heap, so this is fine to use for
+    /// large `N`.
+    ///
+    /// # Example:
+    /// ```
+    /// use hex_str::HexStringN;
+    ///
+    /// let v = HexStringN::<4>::filled(0xab);
+    /// assert_eq!(v, "abababab");
+    /// assert!(v.iter().all(|&v| v == 0xab));
+    /// ```
+    #[must_use]
+    pub fn filled(byte: u8) -> Self {
+        let mut ret: Box<[MaybeUninit<u8>; N]> = unsafe { Box::new_uninit().assume_init() };
+        for v in &mut *ret {
+            v.write(byte);
+        }
+
+        let ret: Box<[u8; N]> = unsafe { core::mem::transmute(ret) };
+        Self::new(ret)
+    }
+
     /// Convert `self` to its string representation, lowercase.
     ///
     /// # Example:
@@ -85,6 +140,116 @@ impl<const N: usize> HexStringN<N> {
             .collect()
     }
 
+    /// Write the string representation of `self` into `buf`, lowercase.
+    ///
+    /// Exactly `2 * self.len()` bytes are written to the beginning of `buf`,
+    /// the rest of `buf` is left untouched. Return the number of bytes
+    /// written.
+    ///
+    /// # Errors
+    /// - if `buf.len() < 2 * self.len()`
+    ///
+    /// # Example:
+    /// ```
+    /// use hex_str::{HexStringN, LengthError};
+    ///
+    /// let v = HexStringN::new([0x1a, 0x2b]);
+    ///
+    /// let mut buf = [0; 4];
+    /// assert_eq!(v.encode_lower_to_buf(&mut buf), Ok(4));
+    /// assert_eq!(std::str::from_utf8(&buf).unwrap(), v.to_lower());
+    ///
+    /// let mut buf = [0; 3];
+    /// let err = v.encode_lower_to_buf(&mut buf).unwrap_err();
+    /// assert_eq!(err, LengthError { expected: 4, encountered: 3 });
+    /// ```
+    pub fn encode_lower_to_buf(&self, buf: &mut [u8]) -> Result<usize, LengthError> {
+        utils::encode_to_buf(&self.0[..], buf, utils::to_hex_lower)
+    }
+
+    /// Write the string representation of `self` into `buf`, uppercase.
+    ///
+    /// Exactly `2 * self.len()` bytes are written to the beginning of `buf`,
+    /// the rest of `buf` is left untouched. Return the number of bytes
+    /// written.
+    ///
+    /// # Errors
+    /// - if `buf.len() < 2 * self.len()`
+    ///
+    /// # Example:
+    /// ```
+    /// use hex_str::HexStringN;
+    ///
+    /// let v = HexStringN::new([0x1a, 0x2b]);
+    ///
+    /// let mut buf = [0; 6];
+    /// assert_eq!(v.encode_upper_to_buf(&mut buf), Ok(4));
+    /// assert_eq!(&buf, b"1A2B\0\0");
+    /// assert_eq!(std::str::from_utf8(&buf[..4]).unwrap(), v.to_upper());
+    /// ```
+    pub fn encode_upper_to_buf(&self, buf: &mut [u8]) -> Result<usize, LengthError> {
+        utils::encode_to_buf(&self.0[..], buf, utils::to_hex_upper)
+    }
+
+    /// Write the string representation of `self` into `w`, lowercase.
+    ///
+    /// Unlike [`HexStringN::to_lower`] this doesn't allocate, characters are
+    /// written to `w` one by one.
+    ///
+    /// # Errors
+    /// - if writing to `w` fails
+    ///
+    /// # Example:
+    /// ```
+    /// use core::fmt::Write;
+    ///
+    /// use hex_str::HexStringN;
+    ///
+    /// struct Counter(usize);
+    ///
+    /// impl Write for Counter {
+    ///     fn write_str(&mut self, s: &str) -> core::fmt::Result {
+    ///         self.0 += s.len();
+    ///         Ok(())
+    ///     }
+    /// }
+    ///
+    /// let v = HexStringN::new([0x1a, 0x2b]);
+    ///
+    /// let mut s = String::new();
+    /// v.write_lower_to(&mut s).unwrap();
+    /// assert_eq!(s, v.to_lower());
+    ///
+    /// let mut counter = Counter(0);
+    /// v.write_lower_to(&mut counter).unwrap();
+    /// assert_eq!(counter.0, 4);
+    /// ```
+    pub fn write_lower_to(&self, w: &mut impl core::fmt::Write) -> core::fmt::Result {
+        utils::write_to(&self.0[..], w, utils::to_hex_lower)
+    }
+
+    /// Write the string representation of `self` into `w`, uppercase.
+    ///
+    /// Unlike [`HexStringN::to_upper`] this doesn't allocate, characters are
+    /// written to `w` one by one.
+    ///
+    /// # Errors
+    /// - if writing to `w` fails
+    ///
+    /// # Example:
+    /// ```
+    /// use hex_str::HexStringN;
+    ///
+    /// let v = HexStringN::new([0x1a, 0x2b]);
+    ///
+    /// let mut s = String::new();
+    /// v.write_upper_to(&mut s).unwrap();
+    /// assert_eq!(s, v.to_upper());
+    /// ```
+    pub fn write_upper_to(&self, w: &mut impl core::fmt::Write) -> core::fmt::Result {
+        utils::write_to(&self.0[..], w, utils::to_hex_upper)
+    }
+
     /// Try to parse `bytes`, both lowercase and uppercase characters allowed.
     ///
     /// This is the same as using [`HexStringN::from_str`]/[`str::parse`] but
@@ -119,7 +284,7 @@ impl<const N: usize> HexStringN<N> {
     /// assert_eq!(v.unwrap(), "1a2b3c4d");
     ///
     /// let v = HexStringN::<4>::try_parse_lower("1A2B3C4D");
-    /// assert_eq!(v.unwrap_err(), HexStringNError::InvalidByte { a: b'1', b: b'A', index: 0 });
+    /// assert_eq!(v.unwrap_err(), HexStringNError::InvalidByte { msb: b'1', lsb: b'A', index: 0 });
     pub fn try_parse_lower(bytes: impl AsRef<[u8]>) -> Result<Self, HexStringNError> {
         try_parse(bytes, utils::parse_lower)
     }
@@ -138,11 +303,95 @@ impl<const N: usize> HexStringN<N> {
     /// assert_eq!(v.unwrap(), "1a2b3c4d");
     ///
     /// let v = HexStringN::<4>::try_parse_upper("1a2b3c4d");
-    /// assert_eq!(v.unwrap_err(), HexStringNError::InvalidByte { a: b'1', b: b'a', index: 0 });
+    /// assert_eq!(v.unwrap_err(), HexStringNError::InvalidByte { msb: b'1', lsb: b'a', index: 0 });
     pub fn try_parse_upper(bytes: impl AsRef<[u8]>) -> Result<Self, HexStringNError> {
         try_parse(bytes, utils::parse_upper)
     }
 
+    /// Try to parse `bytes` prefixed with `0x` or `0X`, both lowercase and
+    /// uppercase characters allowed.
+    ///
+    /// The reported lengths and indices include the prefix, so
+    /// [`HexStringNError::InvalidLength`] expects `2*N + 2` bytes.
+    ///
+    /// # Errors
+    /// - if `bytes` doesn't start with `0x` or `0X`
+    /// - if `bytes.len() != 2*N + 2`
+    /// - if `bytes` contains characters other than `[0-9a-fA-F]` after the prefix
+    ///
+    /// # Example:
+    /// ```
+    /// use hex_str::{HexStringN, HexStringNError};
+    ///
+    /// let v = HexStringN::<2>::try_parse_prefixed("0x1A2b");
+    /// assert_eq!(v.unwrap(), "1a2b");
+    ///
+    /// let v = HexStringN::<2>::try_parse_prefixed("0X1A2b");
+    /// assert_eq!(v.unwrap(), "1a2b");
+    ///
+    /// let v = HexStringN::<0>::try_parse_prefixed("0x");
+    /// assert_eq!(v.unwrap(), "");
+    ///
+    /// let v = HexStringN::<2>::try_parse_prefixed("0x");
+    /// assert_eq!(v.unwrap_err(), HexStringNError::InvalidLength { expected: 6, encountered: 2 });
+    ///
+    /// let v = HexStringN::<2>::try_parse_prefixed("1a2b");
+    /// assert_eq!(v.unwrap_err(), HexStringNError::MissingPrefix);
+    /// ```
+    pub fn try_parse_prefixed(bytes: impl AsRef<[u8]>) -> Result<Self, HexStringNError> {
+        try_parse_prefixed(bytes, utils::parse)
+    }
+
+    /// Try to parse `bytes` prefixed with `0x` or `0X`, only lowercase
+    /// characters allowed.
+    ///
+    /// Only the digits are required to be lowercase, the prefix may be either
+    /// `0x` or `0X`.
+    ///
+    /// # Errors
+    /// - if `bytes` doesn't start with `0x` or `0X`
+    /// - if `bytes.len() != 2*N + 2`
+    /// - if `bytes` contains characters other than `[0-9a-f]` after the prefix
+    ///
+    /// # Example:
+    /// ```
+    /// use hex_str::{HexStringN, HexStringNError};
+    ///
+    /// let v = HexStringN::<2>::try_parse_prefixed_lower("0x1a2b");
+    /// assert_eq!(v.unwrap(), "1a2b");
+    ///
+    /// let v = HexStringN::<2>::try_parse_prefixed_lower("0x1A2B");
+    /// assert_eq!(v.unwrap_err(), HexStringNError::InvalidByte { msb: b'1', lsb: b'A', index: 2 });
+    /// ```
+    pub fn try_parse_prefixed_lower(bytes: impl AsRef<[u8]>) -> Result<Self, HexStringNError> {
+        try_parse_prefixed(bytes, utils::parse_lower)
+    }
+
+    /// Try to parse `bytes` prefixed with `0x` or `0X`, only uppercase
+    /// characters allowed.
+    ///
+    /// Only the digits are required to be uppercase, the prefix may be either
+    /// `0x` or `0X`.
+    ///
+    /// # Errors
+    /// - if `bytes` doesn't start with `0x` or `0X`
+    /// - if `bytes.len() != 2*N + 2`
+    /// - if `bytes` contains characters other than `[0-9A-F]` after the prefix
+    ///
+    /// # Example:
+    /// ```
+    /// use hex_str::{HexStringN, HexStringNError};
+    ///
+    /// let v = HexStringN::<2>::try_parse_prefixed_upper("0x1A2B");
+    /// assert_eq!(v.unwrap(), "1a2b");
+    ///
+    /// let v = HexStringN::<2>::try_parse_prefixed_upper("0x1a2b");
+    /// assert_eq!(v.unwrap_err(), HexStringNError::InvalidByte { msb: b'1', lsb: b'a', index: 2 });
+    /// ```
+    pub fn try_parse_prefixed_upper(bytes: impl AsRef<[u8]>) -> Result<Self, HexStringNError> {
+        try_parse_prefixed(bytes, utils::parse_upper)
+    }
+
     /// Return a reference to the inner array.
     ///
     /// # Example:
@@ -175,328 +424,2996 @@ impl<const N: usize> HexStringN<N> {
     pub fn as_mut_array(&mut self) -> &mut [u8; N] {
         &mut self.0
     }
-}
 
-fn try_parse<const N: usize>(
-    bytes: impl AsRef<[u8]>,
-    conversion_fn: impl Fn(u8, u8) -> Option<u8>,
-) -> Result<HexStringN<N>, HexStringNError> {
-    let bytes = bytes.as_ref();
-    if bytes.len() % 2 != 0 || bytes.len() / 2 != N {
-        return Err(HexStringNError::InvalidLength {
-            expected: N * 2,
-            encountered: bytes.len(),
-        });
+    /// Return the number of ones in the binary representation of `self`.
+    ///
+    /// # Example:
+    /// ```
+    /// use hex_str::HexStringN;
+    ///
+    /// let v = HexStringN::new([0xaa, 0x55, 0x01]);
+    /// assert_eq!(v.count_ones(), 9);
+    ///
+    /// assert_eq!(HexStringN::<4>::zeroed().count_ones(), 0);
+    /// assert_eq!(HexStringN::<4>::ones().count_ones(), 32);
+    /// ```
+    #[must_use]
+    pub fn count_ones(&self) -> u32 {
+        self.0.iter().map(|v| v.count_ones()).sum()
     }
 
-    let mut ret: Box<[MaybeUninit<u8>; N]> = unsafe { Box::new_uninit().assume_init() };
-    let mut i = 0;
-    let mut j = 1;
-    for v in &mut *ret {
-        let a = unsafe { *bytes.get_unchecked(i) };
-        let b = unsafe { *bytes.get_unchecked(j) };
-        conversion_fn(a, b)
-            .ok_or(HexStringNError::InvalidByte { a, b, index: i })
-            .map(|w| v.write(w))?;
-
-        // if len == usize::MAX, this will overflow after the last iteration
-        // which is fine
-        i = i.wrapping_add(2);
-        j = j.wrapping_add(2);
+    /// Return the number of zeros in the binary representation of `self`.
+    ///
+    /// # Example:
+    /// ```
+    /// use hex_str::HexStringN;
+    ///
+    /// let v = HexStringN::new([0xaa, 0x55, 0x01]);
+    /// assert_eq!(v.count_zeros(), 15);
+    ///
+    /// assert_eq!(HexStringN::<4>::zeroed().count_zeros(), 32);
+    /// assert_eq!(HexStringN::<4>::ones().count_zeros(), 0);
+    /// ```
+    #[must_use]
+    pub fn count_zeros(&self) -> u32 {
+        self.0.iter().map(|v| v.count_zeros()).sum()
     }
 
-    let ret: Box<[u8; N]> = unsafe { std::mem::transmute(ret) };
-    Ok(HexStringN::new(ret))
-}
-
-impl<const N: usize> Display for HexStringN<N> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        Display::fmt(&self.to_lower(), f)
+    /// Return the number of leading zeros in the binary representation of
+    /// `self`, treating it as a big-endian integer.
+    ///
+    /// # Example:
+    /// ```
+    /// use hex_str::HexStringN;
+    ///
+    /// assert_eq!(HexStringN::new([0x00, 0x0f]).leading_zeros(), 12);
+    /// assert_eq!(HexStringN::<4>::zeroed().leading_zeros(), 32);
+    /// ```
+    #[must_use]
+    pub fn leading_zeros(&self) -> u32 {
+        match self.0.iter().position(|v| *v != 0) {
+            #[allow(clippy::cast_possible_truncation)]
+            Some(i) => i as u32 * 8 + self.0[i].leading_zeros(),
+            None => self.count_zeros(),
+        }
     }
-}
 
-impl<const N: usize> Debug for HexStringN<N> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("HexStringN")
-            .field("n", &N)
-            .field("inner", &self.to_string())
-            .finish()
+    /// Return the number of trailing zeros in the binary representation of
+    /// `self`, treating it as a big-endian integer.
+    ///
+    /// # Example:
+    /// ```
+    /// use hex_str::HexStringN;
+    ///
+    /// assert_eq!(HexStringN::new([0x80, 0x00]).trailing_zeros(), 15);
+    /// assert_eq!(HexStringN::<4>::zeroed().trailing_zeros(), 32);
+    /// ```
+    #[must_use]
+    pub fn trailing_zeros(&self) -> u32 {
+        match self.0.iter().rev().position(|v| *v != 0) {
+            #[allow(clippy::cast_possible_truncation)]
+            Some(i) => i as u32 * 8 + self.0[N - 1 - i].trailing_zeros(),
+            None => self.count_zeros(),
+        }
     }
-}
-
-impl<const N: usize> FromStr for HexStringN<N> {
-    type Err = HexStringNError;
 
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Self::try_parse(s)
+    /// Return the number of ones in the binary representation of each byte
+    /// of `self`.
+    ///
+    /// # Example:
+    /// ```
+    /// use hex_str::HexStringN;
+    ///
+    /// let v = HexStringN::new([0xaa, 0x55, 0x01, 0x00]);
+    /// assert_eq!(v.count_ones_per_byte(), [4, 4, 1, 0]);
+    /// ```
+    #[must_use]
+    pub fn count_ones_per_byte(&self) -> [u8; N] {
+        #[allow(clippy::cast_possible_truncation)]
+        core::array::from_fn(|i| self.0[i].count_ones() as u8)
     }
-}
 
-impl<const N: usize> From<[u8; N]> for HexStringN<N> {
-    fn from(value: [u8; N]) -> Self {
-        Self::new(value)
+    /// Return the sum of all bytes of `self`.
+    ///
+    /// # Example:
+    /// ```
+    /// use hex_str::HexStringN;
+    ///
+    /// assert_eq!(HexStringN::new([0x01, 0x02, 0xff]).sum_bytes(), 258);
+    /// assert_eq!(HexStringN::<16>::ones().sum_bytes(), 16 * 255);
+    /// ```
+    #[must_use]
+    pub fn sum_bytes(&self) -> u64 {
+        self.0.iter().map(|v| u64::from(*v)).sum()
     }
-}
 
-impl<const N: usize> From<Box<[u8; N]>> for HexStringN<N> {
-    fn from(value: Box<[u8; N]>) -> Self {
-        Self::new(value)
+    /// Return the smallest byte of `self`, `0` if `N == 0`.
+    ///
+    /// # Example:
+    /// ```
+    /// use hex_str::HexStringN;
+    ///
+    /// assert_eq!(HexStringN::new([0x1a, 0x0b, 0xff]).min_byte(), 0x0b);
+    /// assert_eq!(HexStringN::<0>::zeroed().min_byte(), 0);
+    /// ```
+    #[must_use]
+    pub fn min_byte(&self) -> u8 {
+        self.0.iter().copied().min().unwrap_or(0)
     }
-}
 
-impl<const N: usize> From<HexStringN<N>> for Box<[u8; N]> {
-    fn from(value: HexStringN<N>) -> Self {
-        value.0
+    /// Return the largest byte of `self`, `0` if `N == 0`.
+    ///
+    /// # Example:
+    /// ```
+    /// use hex_str::HexStringN;
+    ///
+    /// assert_eq!(HexStringN::new([0x1a, 0x0b, 0xf0]).max_byte(), 0xf0);
+    /// assert_eq!(HexStringN::<0>::zeroed().max_byte(), 0);
+    /// ```
+    #[must_use]
+    pub fn max_byte(&self) -> u8 {
+        self.0.iter().copied().max().unwrap_or(0)
     }
-}
 
-impl<'a, const N: usize> TryFrom<&'a str> for HexStringN<N> {
-    type Error = HexStringNError;
+    /// Return the mean byte value of `self`, `0.0` if `N == 0`.
+    ///
+    /// # Example:
+    /// ```
+    /// use hex_str::HexStringN;
+    ///
+    /// assert_eq!(HexStringN::new([0x00, 0x10, 0x20]).average_byte(), 16.0);
+    /// assert_eq!(HexStringN::<4>::ones().average_byte(), 255.0);
+    /// ```
+    #[must_use]
+    pub fn average_byte(&self) -> f64 {
+        if N == 0 {
+            return 0.0;
+        }
 
-    fn try_from(value: &'a str) -> Result<Self, Self::Error> {
-        value.parse()
+        #[allow(clippy::cast_precision_loss)]
+        let v = self.sum_bytes() as f64 / N as f64;
+        v
     }
-}
-
-impl<const N: usize> TryFrom<String> for HexStringN<N> {
-    type Error = HexStringNError;
+
+    /// Reverse the order of bytes in `self`, in place.
+    ///
+    /// # Example:
+    /// ```
+    /// use hex_str::HexStringN;
+    ///
+    /// let mut v = HexStringN::new([0x1a, 0x2b, 0x3c]);
+    /// v.reverse_bytes();
+    /// assert_eq!(v, "3c2b1a");
+    /// ```
+    pub fn reverse_bytes(&mut self) {
+        self.0.reverse();
+    }
+
+    /// Return a copy of `self` with the order of bytes reversed.
+    ///
+    /// # Example:
+    /// ```
+    /// use hex_str::HexStringN;
+    ///
+    /// let v = HexStringN::new([0x1a, 0x2b, 0x3c]);
+    /// assert_eq!(v.reversed(), "3c2b1a");
+    /// assert_eq!(v.reversed().reversed(), v);
+    /// ```
+    #[must_use]
+    pub fn reversed(&self) -> HexStringN<N> {
+        let mut ret = self.clone();
+        ret.reverse_bytes();
+        ret
+    }
+
+    /// Return a copy of `self` with bytes rotated left by `n` positions.
+    ///
+    /// The first `n % N` bytes are moved to the end.
+    ///
+    /// # Example:
+    /// ```
+    /// use hex_str::HexStringN;
+    ///
+    /// let v = HexStringN::new([0x1a, 0x2b, 0x3c, 0x4d]);
+    /// assert_eq!(v.rotate_left(1), "2b3c4d1a");
+    /// assert_eq!(v.rotate_left(5), "2b3c4d1a");
+    /// ```
+    #[must_use]
+    pub fn rotate_left(&self, n: usize) -> HexStringN<N> {
+        let mut ret = self.clone();
+        ret.rotate_left_inplace(n);
+        ret
+    }
+
+    /// Return a copy of `self` with bytes rotated right by `n` positions.
+    ///
+    /// The last `n % N` bytes are moved to the beginning.
+    ///
+    /// # Example:
+    /// ```
+    /// use hex_str::HexStringN;
+    ///
+    /// let v = HexStringN::new([0x1a, 0x2b, 0x3c, 0x4d]);
+    /// assert_eq!(v.rotate_right(1), "4d1a2b3c");
+    /// assert_eq!(v.rotate_right(5), "4d1a2b3c");
+    /// ```
+    #[must_use]
+    pub fn rotate_right(&self, n: usize) -> HexStringN<N> {
+        let mut ret = self.clone();
+        ret.rotate_right_inplace(n);
+        ret
+    }
+
+    /// Rotate bytes of `self` left by `n` positions, in place.
+    ///
+    /// # Example:
+    /// ```
+    /// use hex_str::HexStringN;
+    ///
+    /// let mut v = HexStringN::new([0x1a, 0x2b, 0x3c, 0x4d]);
+    /// v.rotate_left_inplace(2);
+    /// assert_eq!(v, "3c4d1a2b");
+    /// ```
+    pub fn rotate_left_inplace(&mut self, n: usize) {
+        if N != 0 {
+            self.0.rotate_left(n % N);
+        }
+    }
+
+    /// Rotate bytes of `self` right by `n` positions, in place.
+    ///
+    /// # Example:
+    /// ```
+    /// use hex_str::HexStringN;
+    ///
+    /// let mut v = HexStringN::new([0x1a, 0x2b, 0x3c, 0x4d]);
+    /// v.rotate_right_inplace(3);
+    /// assert_eq!(v, "2b3c4d1a");
+    /// ```
+    pub fn rotate_right_inplace(&mut self, n: usize) {
+        if N != 0 {
+            self.0.rotate_right(n % N);
+        }
+    }
+
+    /// Return a copy of `self` with bytes shifted left by `n` positions.
+    ///
+    /// Byte `i` is moved to `i - n`, the last `n` bytes are zeroed. If
+    /// `n >= N` the result is zeroed. See [`HexStringN::shl_bits`] for a
+    /// bit-level shift.
+    ///
+    /// # Example:
+    /// ```
+    /// use hex_str::HexStringN;
+    ///
+    /// let v = HexStringN::new([0x01, 0x02, 0x03]);
+    /// assert_eq!(v.shl_bytes(1), "020300");
+    /// assert_eq!(v.shl_bytes(3), "000000");
+    /// ```
+    #[must_use]
+    pub fn shl_bytes(&self, n: usize) -> HexStringN<N> {
+        let mut ret = self.clone();
+        ret.shl_bytes_inplace(n);
+        ret
+    }
+
+    /// Return a copy of `self` with bytes shifted right by `n` positions.
+    ///
+    /// Byte `i` is moved to `i + n`, the first `n` bytes are zeroed. If
+    /// `n >= N` the result is zeroed. See [`HexStringN::shr_bits`] for a
+    /// bit-level shift.
+    ///
+    /// # Example:
+    /// ```
+    /// use hex_str::HexStringN;
+    ///
+    /// let v = HexStringN::new([0x01, 0x02, 0x03]);
+    /// assert_eq!(v.shr_bytes(1), "000102");
+    /// assert_eq!(v.shr_bytes(4), "000000");
+    /// ```
+    #[must_use]
+    pub fn shr_bytes(&self, n: usize) -> HexStringN<N> {
+        let mut ret = self.clone();
+        ret.shr_bytes_inplace(n);
+        ret
+    }
+
+    /// Shift bytes of `self` left by `n` positions, in place.
+    ///
+    /// # Example:
+    /// ```
+    /// use hex_str::HexStringN;
+    ///
+    /// let mut v = HexStringN::new([0x1a, 0x2b, 0x3c, 0x4d]);
+    /// v.shl_bytes_inplace(2);
+    /// assert_eq!(v, "3c4d0000");
+    /// ```
+    pub fn shl_bytes_inplace(&mut self, n: usize) {
+        let n = n.min(N);
+        self.0.copy_within(n.., 0);
+        self.0[N - n..].fill(0);
+    }
+
+    /// Shift bytes of `self` right by `n` positions, in place.
+    ///
+    /// # Example:
+    /// ```
+    /// use hex_str::HexStringN;
+    ///
+    /// let mut v = HexStringN::new([0x1a, 0x2b, 0x3c, 0x4d]);
+    /// v.shr_bytes_inplace(3);
+    /// assert_eq!(v, "0000001a");
+    /// ```
+    pub fn shr_bytes_inplace(&mut self, n: usize) {
+        let n = n.min(N);
+        self.0.copy_within(..N - n, n);
+        self.0[..n].fill(0);
+    }
+
+    /// Return a copy of `self` shifted left by `n` bits, treating `self` as
+    /// a big-endian integer.
+    ///
+    /// Bits shifted past the first byte are discarded, if `n >= 8 * N` the
+    /// result is zeroed.
+    ///
+    /// # Example:
+    /// ```
+    /// use hex_str::HexStringN;
+    ///
+    /// let v = HexStringN::new([0x01, 0x80, 0xff]);
+    /// assert_eq!(v.shl_bits(1), "0301fe");
+    /// assert_eq!(v.shl_bits(12), "0ff000");
+    /// ```
+    #[must_use]
+    pub fn shl_bits(&self, n: usize) -> HexStringN<N> {
+        let mut ret = self.clone();
+        ret.shl_bits_inplace(n);
+        ret
+    }
+
+    /// Return a copy of `self` shifted right by `n` bits, treating `self` as
+    /// a big-endian integer.
+    ///
+    /// Bits shifted past the last byte are discarded, if `n >= 8 * N` the
+    /// result is zeroed.
+    ///
+    /// # Example:
+    /// ```
+    /// use hex_str::HexStringN;
+    ///
+    /// let v = HexStringN::new([0x01, 0x80, 0xff]);
+    /// assert_eq!(v.shr_bits(1), "00c07f");
+    /// assert_eq!(v.shr_bits(12), "000018");
+    /// ```
+    #[must_use]
+    pub fn shr_bits(&self, n: usize) -> HexStringN<N> {
+        let mut ret = self.clone();
+        ret.shr_bits_inplace(n);
+        ret
+    }
+
+    /// Shift `self` left by `n` bits, treating it as a big-endian integer,
+    /// in place.
+    ///
+    /// # Example:
+    /// ```
+    /// use hex_str::HexStringN;
+    ///
+    /// let mut v = HexStringN::new([0x00, 0x0f]);
+    /// v.shl_bits_inplace(4);
+    /// assert_eq!(v, "00f0");
+    /// ```
+    pub fn shl_bits_inplace(&mut self, n: usize) {
+        self.shl_bytes_inplace(n / 8);
+
+        let bits = n % 8;
+        if bits != 0 {
+            for i in 0..N {
+                let next = self.0.get(i + 1).copied().unwrap_or(0);
+                self.0[i] = (self.0[i] << bits) | (next >> (8 - bits));
+            }
+        }
+    }
+
+    /// Shift `self` right by `n` bits, treating it as a big-endian integer,
+    /// in place.
+    ///
+    /// # Example:
+    /// ```
+    /// use hex_str::HexStringN;
+    ///
+    /// let mut v = HexStringN::new([0xf0, 0x00]);
+    /// v.shr_bits_inplace(4);
+    /// assert_eq!(v, "0f00");
+    /// ```
+    pub fn shr_bits_inplace(&mut self, n: usize) {
+        self.shr_bytes_inplace(n / 8);
+
+        let bits = n % 8;
+        if bits != 0 {
+            for i in (0..N).rev() {
+                let prev = if i == 0 { 0 } else { self.0[i - 1] };
+                self.0[i] = (self.0[i] >> bits) | (prev << (8 - bits));
+            }
+        }
+    }
+
+    /// Return the number of bits that differ between `self` and `other`.
+    ///
+    /// # Example:
+    /// ```
+    /// use hex_str::HexStringN;
+    ///
+    /// let a = HexStringN::new([0b1010_1010, 0x00]);
+    /// let b = HexStringN::new([0b1010_0101, 0x01]);
+    /// assert_eq!(a.hamming_distance(&b), 5);
+    /// assert_eq!(a.hamming_distance(&a), 0);
+    /// ```
+    #[must_use]
+    pub fn hamming_distance(&self, other: &HexStringN<N>) -> u32 {
+        self.0
+            .iter()
+            .zip(&*other.0)
+            .map(|(a, b)| (a ^ b).count_ones())
+            .sum()
+    }
+
+    /// Return the number of bits that differ between `self` and `other`.
+    ///
+    /// # Errors
+    /// - if `other.len() != N`
+    ///
+    /// # Example:
+    /// ```
+    /// use hex_str::{HexStringN, LengthError};
+    ///
+    /// let a = HexStringN::new([0b1010_1010, 0x00]);
+    /// assert_eq!(a.hamming_distance_bytes(&[0b1010_0101, 0x01]), Ok(5));
+    /// assert_eq!(
+    ///     a.hamming_distance_bytes(&[0x00]),
+    ///     Err(LengthError { expected: 2, encountered: 1 })
+    /// );
+    /// ```
+    pub fn hamming_distance_bytes(&self, other: &[u8]) -> Result<u32, LengthError> {
+        if other.len() != N {
+            return Err(LengthError {
+                expected: N,
+                encountered: other.len(),
+            });
+        }
+
+        Ok(self
+            .0
+            .iter()
+            .zip(other)
+            .map(|(a, b)| (a ^ b).count_ones())
+            .sum())
+    }
+
+    /// Return `true` if all bytes of `self` are `0x00`.
+    ///
+    /// # Example:
+    /// ```
+    /// use hex_str::HexStringN;
+    ///
+    /// assert!(HexStringN::<4>::zeroed().is_zero());
+    /// assert!(!HexStringN::new([0x00, 0x01]).is_zero());
+    /// ```
+    #[must_use]
+    pub fn is_zero(&self) -> bool {
+        self.0.iter().all(|&v| v == 0x00)
+    }
+
+    /// Return `true` if all bytes of `self` are `0xff`.
+    ///
+    /// # Example:
+    /// ```
+    /// use hex_str::HexStringN;
+    ///
+    /// assert!(HexStringN::<4>::ones().is_all_ones());
+    /// assert!(!HexStringN::new([0xfe, 0xff]).is_all_ones());
+    /// ```
+    #[must_use]
+    pub fn is_all_ones(&self) -> bool {
+        self.0.iter().all(|&v| v == 0xff)
+    }
+
+    /// Convert `self` into a `Vec<u8>`, without copying.
+    ///
+    /// # Example:
+    /// ```
+    /// use hex_str::HexStringN;
+    ///
+    /// let v = HexStringN::new([0x1a, 0x2b]);
+    /// assert_eq!(v.into_vec(), vec![0x1a, 0x2b]);
+    /// ```
+    #[must_use]
+    pub fn into_vec(self) -> Vec<u8> {
+        let ret: Box<[u8]> = self.0;
+        ret.into_vec()
+    }
+
+    /// Copy `self` into a new [`HexString`].
+    ///
+    /// # Example:
+    /// ```
+    /// use hex_str::HexStringN;
+    ///
+    /// let v = HexStringN::new([0x1a, 0x2b]);
+    /// assert_eq!(v.to_hex_string().as_slice(), v.as_slice());
+    /// ```
+    #[must_use]
+    pub fn to_hex_string(&self) -> HexString {
+        HexString::new(&self.0[..])
+    }
+
+    /// Return an iterator over the bytes of `self`.
+    ///
+    /// # Example:
+    /// ```
+    /// use hex_str::HexStringN;
+    ///
+    /// let v = HexStringN::new([0x01, 0x02, 0x03]);
+    /// assert_eq!(v.iter().map(|&v| u32::from(v)).sum::<u32>(), 6);
+    /// ```
+    pub fn iter(&self) -> core::slice::Iter<'_, u8> {
+        self.0.iter()
+    }
+
+    /// Return an iterator over the bytes of `self` that allows modifying
+    /// each byte.
+    ///
+    /// # Example:
+    /// ```
+    /// use hex_str::HexStringN;
+    ///
+    /// let mut v = HexStringN::new([0x00, 0xff]);
+    /// v.iter_mut().for_each(|v| *v ^= 0xaa);
+    /// assert_eq!(v, "aa55");
+    /// ```
+    pub fn iter_mut(&mut self) -> core::slice::IterMut<'_, u8> {
+        self.0.iter_mut()
+    }
+
+    /// Copy the bytes of `self` into `dst`.
+    ///
+    /// # Errors
+    /// - if `dst.len() != N`
+    ///
+    /// # Example:
+    /// ```
+    /// use hex_str::{HexStringN, LengthError};
+    ///
+    /// let v = HexStringN::new([0x1a, 0x2b]);
+    ///
+    /// let mut dst = [0; 2];
+    /// v.copy_into(&mut dst).unwrap();
+    /// assert_eq!(dst, [0x1a, 0x2b]);
+    ///
+    /// let mut dst = [0; 3];
+    /// let err = v.copy_into(&mut dst).unwrap_err();
+    /// assert_eq!(err, LengthError { expected: 2, encountered: 3 });
+    /// ```
+    pub fn copy_into(&self, dst: &mut [u8]) -> Result<(), LengthError> {
+        if dst.len() != N {
+            return Err(LengthError {
+                expected: N,
+                encountered: dst.len(),
+            });
+        }
+
+        dst.copy_from_slice(&self.0[..]);
+        Ok(())
+    }
+
+    /// Return a raw pointer to the inner array.
+    ///
+    /// # Example:
+    /// ```
+    /// use hex_str::HexStringN;
+    ///
+    /// let v = HexStringN::new([0x1a, 0x2b]);
+    /// assert_eq!(v.as_ptr(), v.as_array().as_ptr());
+    /// ```
+    #[must_use]
+    pub fn as_ptr(&self) -> *const u8 {
+        self.0.as_ptr()
+    }
+
+    /// Return a raw mutable pointer to the inner array.
+    ///
+    /// # Example:
+    /// ```
+    /// use hex_str::HexStringN;
+    ///
+    /// let mut v = HexStringN::new([0x1a, 0x2b]);
+    /// let ptr = v.as_mut_ptr();
+    /// assert_eq!(ptr.cast_const(), v.as_array().as_ptr());
+    ///
+    /// unsafe { *ptr = 0xff };
+    /// assert_eq!(v, "ff2b");
+    /// ```
+    #[must_use]
+    pub fn as_mut_ptr(&mut self) -> *mut u8 {
+        self.0.as_mut_ptr()
+    }
+
+    /// Parse `bytes` and overwrite `self` with the result, both lowercase and
+    /// uppercase characters allowed.
+    ///
+    /// `bytes` is validated before anything is written, so `self` is left
+    /// untouched on failure. No allocation takes place.
+    ///
+    /// # Errors
+    /// - if `bytes.len() != 2*N`
+    /// - if `bytes` contains characters other than `[0-9a-fA-F]`
+    ///
+    /// # Example:
+    /// ```
+    /// use hex_str::HexStringN;
+    ///
+    /// let mut v = HexStringN::new([0x1a, 0x2b]);
+    ///
+    /// v.overwrite_from_hex("3C4d").unwrap();
+    /// assert_eq!(v, "3c4d");
+    ///
+    /// v.overwrite_from_hex("5e6g").unwrap_err();
+    /// assert_eq!(v, "3c4d");
+    /// ```
+    pub fn overwrite_from_hex(&mut self, bytes: impl AsRef<[u8]>) -> Result<(), HexStringNError> {
+        overwrite_from_hex(&mut self.0, bytes, utils::parse)
+    }
+
+    /// Parse `bytes` and overwrite `self` with the result, only lowercase
+    /// characters allowed.
+    ///
+    /// `bytes` is validated before anything is written, so `self` is left
+    /// untouched on failure. No allocation takes place.
+    ///
+    /// # Errors
+    /// - if `bytes.len() != 2*N`
+    /// - if `bytes` contains characters other than `[0-9a-f]`
+    ///
+    /// # Example:
+    /// ```
+    /// use hex_str::{HexStringN, HexStringNError};
+    ///
+    /// let mut v = HexStringN::new([0x1a, 0x2b]);
+    ///
+    /// v.overwrite_from_hex_lower("3c4d").unwrap();
+    /// assert_eq!(v, "3c4d");
+    ///
+    /// let err = v.overwrite_from_hex_lower("5e6F").unwrap_err();
+    /// assert_eq!(err, HexStringNError::InvalidByte { msb: b'6', lsb: b'F', index: 2 });
+    /// assert_eq!(v, "3c4d");
+    /// ```
+    pub fn overwrite_from_hex_lower(
+        &mut self,
+        bytes: impl AsRef<[u8]>,
+    ) -> Result<(), HexStringNError> {
+        overwrite_from_hex(&mut self.0, bytes, utils::parse_lower)
+    }
+
+    /// Parse `bytes` and overwrite `self` with the result, only uppercase
+    /// characters allowed.
+    ///
+    /// `bytes` is validated before anything is written, so `self` is left
+    /// untouched on failure. No allocation takes place.
+    ///
+    /// # Errors
+    /// - if `bytes.len() != 2*N`
+    /// - if `bytes` contains characters other than `[0-9A-F]`
+    ///
+    /// # Example:
+    /// ```
+    /// use hex_str::{HexStringN, HexStringNError};
+    ///
+    /// let mut v = HexStringN::new([0x1a, 0x2b]);
+    ///
+    /// v.overwrite_from_hex_upper("3C4D").unwrap();
+    /// assert_eq!(v, "3c4d");
+    ///
+    /// let err = v.overwrite_from_hex_upper("5E6").unwrap_err();
+    /// assert_eq!(err, HexStringNError::InvalidLength { expected: 4, encountered: 3 });
+    /// assert_eq!(v, "3c4d");
+    /// ```
+    pub fn overwrite_from_hex_upper(
+        &mut self,
+        bytes: impl AsRef<[u8]>,
+    ) -> Result<(), HexStringNError> {
+        overwrite_from_hex(&mut self.0, bytes, utils::parse_upper)
+    }
+
+    /// Return a view of `self` as a slice of `N / M` arrays of length `M`.
+    ///
+    /// This is a zero-copy cast, `N` has to be divisible by `M`, which is
+    /// checked at compile time.
+    ///
+    /// # Example:
+    /// ```
+    /// use hex_str::HexStringN;
+    ///
+    /// let v: HexStringN<6> = "1a2b3c4d5e6f".parse().unwrap();
+    ///
+    /// let chunks = v.chunks_as::<2>();
+    /// assert_eq!(chunks, [[0x1a, 0x2b], [0x3c, 0x4d], [0x5e, 0x6f]]);
+    /// ```
+    ///
+    /// `N` not being divisible by `M` fails to compile:
+    /// ```compile_fail
+    /// use hex_str::HexStringN;
+    ///
+    /// let v: HexStringN<6> = "1a2b3c4d5e6f".parse().unwrap();
+    /// let _ = v.chunks_as::<4>();
+    /// ```
+    #[must_use]
+    pub fn chunks_as<const M: usize>(&self) -> &[[u8; M]] {
+        const { assert!(M != 0 && N % M == 0, "`N` must be divisible by `M`") };
+
+        // Safety: `[u8; N]` has the same layout as `[[u8; M]; N / M]`
+        unsafe { core::slice::from_raw_parts(self.0.as_ptr().cast(), N / M) }
+    }
+
+    /// Return a mutable view of `self` as a slice of `N / M` arrays of length
+    /// `M`.
+    ///
+    /// This is a zero-copy cast, `N` has to be divisible by `M`, which is
+    /// checked at compile time.
+    ///
+    /// # Example:
+    /// ```
+    /// use hex_str::HexStringN;
+    ///
+    /// let mut v: HexStringN<6> = "1a2b3c4d5e6f".parse().unwrap();
+    ///
+    /// for chunk in v.chunks_as_mut::<3>() {
+    ///     chunk.reverse();
+    /// }
+    /// assert_eq!(v, "3c2b1a6f5e4d");
+    /// ```
+    #[must_use]
+    pub fn chunks_as_mut<const M: usize>(&mut self) -> &mut [[u8; M]] {
+        const { assert!(M != 0 && N % M == 0, "`N` must be divisible by `M`") };
+
+        // Safety: `[u8; N]` has the same layout as `[[u8; M]; N / M]`
+        unsafe { core::slice::from_raw_parts_mut(self.0.as_mut_ptr().cast(), N / M) }
+    }
+
+    /// Return an iterator over the nibbles of every byte of `self`, as
+    /// `(most significant, least significant)` pairs, each in `0..=15`.
+    ///
+    /// # Example:
+    /// ```
+    /// use hex_str::HexStringN;
+    ///
+    /// let v = HexStringN::new([0xab, 0x1c]);
+    /// let pairs: Vec<_> = v.iter_hex_pairs().collect();
+    /// assert_eq!(pairs, [(0x0a, 0x0b), (0x01, 0x0c)]);
+    /// ```
+    #[must_use]
+    pub fn iter_hex_pairs(&self) -> impl ExactSizeIterator<Item = (u8, u8)> + '_ {
+        self.0.iter().map(|&v| utils::split_nibbles(v))
+    }
+
+    /// Return an iterator over the nibbles of `self`, each in `0..=15`, most
+    /// significant nibble of every byte first.
+    ///
+    /// # Example:
+    /// ```
+    /// use hex_str::HexStringN;
+    ///
+    /// let v = HexStringN::new([0xab, 0x1c]);
+    /// let nibbles: Vec<_> = v.iter_nibbles().collect();
+    /// assert_eq!(nibbles, [0x0a, 0x0b, 0x01, 0x0c]);
+    /// ```
+    #[must_use]
+    pub fn iter_nibbles(&self) -> impl DoubleEndedIterator<Item = u8> + '_ {
+        self.0.iter().flat_map(|&v| {
+            let (a, b) = utils::split_nibbles(v);
+            [a, b]
+        })
+    }
+
+    /// Try to parse `bytes`, both lowercase and uppercase characters allowed,
+    /// collecting every invalid pair instead of stopping at the first one.
+    ///
+    /// # Errors
+    /// - if `bytes.len() != 2 * N`, the only error returned
+    /// - if `bytes` contains characters other than `[0-9a-fA-F]`, one error
+    ///   per invalid pair, in order of appearance
+    ///
+    /// # Example:
+    /// ```
+    /// use hex_str::{HexStringN, HexStringNError};
+    ///
+    /// let v = HexStringN::<2>::try_parse_collect_errors("1a2b");
+    /// assert_eq!(v.unwrap(), "1a2b");
+    ///
+    /// let errors = HexStringN::<4>::try_parse_collect_errors("zz2b3cg0").unwrap_err();
+    /// assert_eq!(
+    ///     errors,
+    ///     [
+    ///         HexStringNError::InvalidByte { msb: b'z', lsb: b'z', index: 0 },
+    ///         HexStringNError::InvalidByte { msb: b'g', lsb: b'0', index: 6 },
+    ///     ]
+    /// );
+    /// ```
+    pub fn try_parse_collect_errors(bytes: impl AsRef<[u8]>) -> Result<Self, Vec<HexStringNError>> {
+        let bytes = bytes.as_ref();
+        if bytes.len() != N * 2 {
+            return Err(vec![HexStringNError::InvalidLength {
+                expected: N * 2,
+                encountered: bytes.len(),
+            }]);
+        }
+
+        let mut errors = Vec::new();
+        let v: Vec<u8> = bytes
+            .chunks_exact(2)
+            .enumerate()
+            .map(|(i, pair)| {
+                let (msb, lsb) = (pair[0], pair[1]);
+                utils::parse(msb, lsb).unwrap_or_else(|| {
+                    errors.push(HexStringNError::InvalidByte {
+                        msb,
+                        lsb,
+                        index: i * 2,
+                    });
+                    0
+                })
+            })
+            .collect();
+
+        if errors.is_empty() {
+            Ok(Self::try_from(v).unwrap_or_else(|_| unreachable!()))
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Try to parse `bytes` consisting of pairs separated by `sep`, both
+    /// lowercase and uppercase characters allowed.
+    ///
+    /// Used for MAC addresses and fingerprints, such as `01:23:45:67:89:ab`.
+    /// [`HexStringNError::InvalidLength`] expects `3*N - 1` bytes, or none if
+    /// `N == 0`.
+    ///
+    /// Every pair must be separated, so canonical `8-4-4-4-12` UUIDs such as
+    /// `67e55044-10b1-426f-9247-bb680e5fe0c8` are rejected, parse them with
+    /// `HexStringN::<16>::from_uuid_str` from the `uuid` feature instead.
+    ///
+    /// # Errors
+    /// - if `bytes` doesn't contain exactly `N` pairs
+    /// - if `sep` is found inside of a pair
+    /// - if pairs aren't separated by `sep`
+    /// - if `bytes` contains characters other than `[0-9a-fA-F]` inside of
+    ///   pairs
+    ///
+    /// # Example:
+    /// ```
+    /// use hex_str::{HexStringN, HexStringNError};
+    ///
+    /// let v = HexStringN::<6>::try_parse_separated("01:23:45:67:89:AB", b':');
+    /// assert_eq!(v.unwrap(), "0123456789ab");
+    ///
+    /// let v = HexStringN::<3>::try_parse_separated("01-23-45", b'-');
+    /// assert_eq!(v.unwrap(), "012345");
+    ///
+    /// let v = HexStringN::<3>::try_parse_separated("01:2:345", b':');
+    /// assert_eq!(v.unwrap_err(), HexStringNError::UnexpectedSeparator { position: 4 });
+    ///
+    /// let v = HexStringN::<3>::try_parse_separated("01:2345", b':');
+    /// assert_eq!(v.unwrap_err(), HexStringNError::MissingSeparator { position: 5 });
+    ///
+    /// let v = HexStringN::<3>::try_parse_separated("01:23", b':');
+    /// assert_eq!(v.unwrap_err(), HexStringNError::InvalidLength { expected: 8, encountered: 5 });
+    /// ```
+    pub fn try_parse_separated(bytes: impl AsRef<[u8]>, sep: u8) -> Result<Self, HexStringNError> {
+        let bytes = bytes.as_ref();
+        let length_error = HexStringNError::InvalidLength {
+            expected: (N * 3).saturating_sub(1),
+            encountered: bytes.len(),
+        };
+
+        let v = utils::parse_separated(bytes, sep, utils::parse).map_err(|err| match err {
+            utils::SeparatedError::Truncated => length_error.clone(),
+            utils::SeparatedError::InvalidByte { msb, lsb, index } => {
+                HexStringNError::InvalidByte { msb, lsb, index }
+            }
+            utils::SeparatedError::UnexpectedSeparator { position } => {
+                HexStringNError::UnexpectedSeparator { position }
+            }
+            utils::SeparatedError::MissingSeparator { position } => {
+                HexStringNError::MissingSeparator { position }
+            }
+        })?;
+
+        Self::try_from(v).map_err(|_| length_error)
+    }
+
+    /// Convert `self` to its string representation, lowercase, with `sep`
+    /// inserted after every `group_size` bytes.
+    ///
+    /// The last group is never followed by `sep`.
+    ///
+    /// # Panics
+    /// - if `group_size == 0`
+    ///
+    /// # Example:
+    /// ```
+    /// use hex_str::HexStringN;
+    ///
+    /// let v = HexStringN::new([0x1a, 0x2b, 0x3c, 0x4d]);
+    /// assert_eq!(v.format_separated(':', 1), "1a:2b:3c:4d");
+    /// assert_eq!(v.format_separated(' ', 2), "1a2b 3c4d");
+    /// assert_eq!(v.format_separated(' ', 3), "1a2b3c 4d");
+    /// ```
+    #[must_use]
+    pub fn format_separated(&self, sep: char, group_size: usize) -> String {
+        let mut ret = String::new();
+        self.write_separated_to(&mut ret, sep, group_size)
+            .unwrap_or_else(|_| unreachable!());
+        ret
+    }
+
+    /// Write the string representation of `self` into `w`, lowercase, with
+    /// `sep` inserted after every `group_size` bytes.
+    ///
+    /// Unlike [`HexStringN::format_separated`] this doesn't allocate.
+    ///
+    /// # Errors
+    /// - if writing to `w` fails
+    ///
+    /// # Panics
+    /// - if `group_size == 0`
+    ///
+    /// # Example:
+    /// ```
+    /// use hex_str::HexStringN;
+    ///
+    /// let v = HexStringN::new([0x1a, 0x2b, 0x3c, 0x4d]);
+    ///
+    /// let mut s = String::from("fingerprint: ");
+    /// v.write_separated_to(&mut s, ':', 1).unwrap();
+    /// assert_eq!(s, "fingerprint: 1a:2b:3c:4d");
+    /// ```
+    pub fn write_separated_to(
+        &self,
+        w: &mut impl core::fmt::Write,
+        sep: char,
+        group_size: usize,
+    ) -> core::fmt::Result {
+        utils::write_separated_to(&self.0[..], w, sep, group_size, utils::to_hex_lower)
+    }
+
+    /// Compare `self` and `other` without exiting early on the first
+    /// mismatched byte.
+    ///
+    /// All `N` bytes are always XOR-accumulated, so the time taken doesn't
+    /// depend on where the first difference is, making this suitable for
+    /// comparing MACs or tokens.
+    ///
+    /// This is best effort: nothing stops the optimizer from introducing
+    /// branches. For guarantees enable the `subtle` feature and use
+    /// `subtle::ConstantTimeEq`, which `HexStringN` implements.
+    ///
+    /// # Example:
+    /// ```
+    /// use hex_str::HexStringN;
+    ///
+    /// let expected = HexStringN::new([0x1a, 0x2b, 0x3c, 0x4d]);
+    ///
+    /// assert!(expected.ct_eq_bool(&HexStringN::new([0x1a, 0x2b, 0x3c, 0x4d])));
+    /// assert!(!expected.ct_eq_bool(&HexStringN::new([0x1a, 0x2b, 0x3c, 0x4e])));
+    /// ```
+    #[must_use]
+    pub fn ct_eq_bool(&self, other: &HexStringN<N>) -> bool {
+        let acc = self
+            .0
+            .iter()
+            .zip(other.0.iter())
+            .fold(0, |acc, (a, b)| core::hint::black_box(acc | (a ^ b)));
+
+        acc == 0
+    }
+
+    /// Create a new `HexStringN` filled with cryptographically secure random
+    /// bytes, from the operating system's source.
+    ///
+    /// # Errors
+    /// - if `getrandom` fails to provide random bytes
+    ///
+    /// # Example:
+    /// ```
+    /// use hex_str::HexStringN;
+    ///
+    /// let v = HexStringN::<16>::try_random().unwrap();
+    /// assert_eq!(v.to_lower().len(), 32);
+    /// ```
+    #[cfg(feature = "getrandom")]
+    pub fn try_random() -> Result<Self, getrandom::Error> {
+        let mut ret = Self::zeroed();
+        getrandom::getrandom(&mut ret.0[..])?;
+
+        Ok(ret)
+    }
+
+    /// Return a new `HexStringN` with `f` applied to every byte of `self`.
+    ///
+    /// # Example:
+    /// ```
+    /// use hex_str::HexStringN;
+    ///
+    /// let v = HexStringN::new([0x1a, 0x2b]);
+    /// assert_eq!(v.map(|v| v ^ 0xaa), "b081");
+    /// assert_eq!(v.map(|v| !v), !&v);
+    /// ```
+    #[must_use]
+    pub fn map(&self, f: impl FnMut(u8) -> u8) -> HexStringN<N> {
+        let mut ret = self.clone();
+        ret.map_inplace(f);
+        ret
+    }
+
+    /// Apply `f` to every byte of `self`, in place.
+    ///
+    /// # Example:
+    /// ```
+    /// use hex_str::HexStringN;
+    ///
+    /// let mut v = HexStringN::new([0x1a, 0x2b]);
+    /// v.map_inplace(|v| v.rotate_left(4));
+    /// assert_eq!(v, "a1b2");
+    /// ```
+    pub fn map_inplace(&mut self, mut f: impl FnMut(u8) -> u8) {
+        self.0.iter_mut().for_each(|v| *v = f(*v));
+    }
+
+    /// Return a new `HexStringN` with `f` applied to every pair of bytes of
+    /// `self` and `other`.
+    ///
+    /// # Example:
+    /// ```
+    /// use hex_str::HexStringN;
+    ///
+    /// let a = HexStringN::new([0x1a, 0x2b]);
+    /// let b = HexStringN::new([0xff, 0x0f]);
+    /// assert_eq!(a.zip_with(&b, |a, b| a & b), "1a0b");
+    /// assert_eq!(a.zip_with(&b, |a, b| a ^ b), &a ^ &b);
+    /// ```
+    #[must_use]
+    pub fn zip_with(&self, other: &HexStringN<N>, f: impl FnMut(u8, u8) -> u8) -> HexStringN<N> {
+        let mut ret = self.clone();
+        ret.zip_with_inplace(other, f);
+        ret
+    }
+
+    /// Apply `f` to every pair of bytes of `self` and `other`, storing the
+    /// result in `self`.
+    ///
+    /// # Example:
+    /// ```
+    /// use hex_str::HexStringN;
+    ///
+    /// let mut v = HexStringN::new([0x1a, 0x2b]);
+    /// v.zip_with_inplace(&HexStringN::new([0x01, 0x02]), u8::wrapping_add);
+    /// assert_eq!(v, "1b2d");
+    /// ```
+    pub fn zip_with_inplace(&mut self, other: &HexStringN<N>, mut f: impl FnMut(u8, u8) -> u8) {
+        self.0
+            .iter_mut()
+            .zip(other.0.iter())
+            .for_each(|(a, &b)| *a = f(*a, b));
+    }
+
+    /// Try to parse `bytes`, both lowercase and uppercase characters
+    /// allowed, ignoring any ASCII whitespace.
+    ///
+    /// Useful for hex dumps copied from terminals or documentation. Errors
+    /// are reported as if `bytes` had its whitespace removed.
+    ///
+    /// # Errors
+    /// - if `bytes` without whitespace isn't of a valid length
+    /// - if `bytes` contains characters other than `[0-9a-fA-F]` and
+    ///   whitespace
+    ///
+    /// # Example:
+    /// ```
+    /// use hex_str::{HexStringN, HexStringNError};
+    ///
+    /// let v = HexStringN::<2>::try_parse_lax(" 1a 2B\n");
+    /// assert_eq!(v.unwrap(), "1a2b");
+    ///
+    /// let v = HexStringN::<2>::try_parse_lax("1a\t2");
+    /// assert_eq!(v.unwrap_err(), HexStringNError::InvalidLength { expected: 4, encountered: 3 });
+    /// ```
+    pub fn try_parse_lax(bytes: impl AsRef<[u8]>) -> Result<Self, HexStringNError> {
+        let bytes: Vec<u8> = bytes
+            .as_ref()
+            .iter()
+            .copied()
+            .filter(|v| !v.is_ascii_whitespace())
+            .collect();
+
+        Self::try_parse(bytes)
+    }
+
+    /// Return a new `HexStringN` with every byte of `other` added to the
+    /// corresponding byte of `self`, with wrapping arithmetic.
+    ///
+    /// # Example:
+    /// ```
+    /// use hex_str::HexStringN;
+    ///
+    /// let v = HexStringN::new([0xff, 0x2b]);
+    /// assert_eq!(v.wrapping_add_bytes(&HexStringN::new([0x01, 0x01])), "002c");
+    /// ```
+    #[must_use]
+    pub fn wrapping_add_bytes(&self, other: &HexStringN<N>) -> HexStringN<N> {
+        self.zip_with(other, u8::wrapping_add)
+    }
+
+    /// Add every byte of `other` to the corresponding byte of `self`, with
+    /// wrapping arithmetic, in place.
+    ///
+    /// # Example:
+    /// ```
+    /// use hex_str::HexStringN;
+    ///
+    /// let mut v = HexStringN::new([0xff, 0x2b]);
+    /// v.wrapping_add_bytes_inplace(&HexStringN::new([0x01, 0x01]));
+    /// assert_eq!(v, "002c");
+    /// ```
+    pub fn wrapping_add_bytes_inplace(&mut self, other: &HexStringN<N>) {
+        self.zip_with_inplace(other, u8::wrapping_add);
+    }
+
+    /// Return a new `HexStringN` with every byte of `other` subtracted from
+    /// the corresponding byte of `self`, with wrapping arithmetic.
+    ///
+    /// # Example:
+    /// ```
+    /// use hex_str::HexStringN;
+    ///
+    /// let v = HexStringN::new([0x00, 0x2b]);
+    /// assert_eq!(v.wrapping_sub_bytes(&HexStringN::new([0x01, 0x01])), "ff2a");
+    /// ```
+    #[must_use]
+    pub fn wrapping_sub_bytes(&self, other: &HexStringN<N>) -> HexStringN<N> {
+        self.zip_with(other, u8::wrapping_sub)
+    }
+
+    /// Subtract every byte of `other` from the corresponding byte of `self`,
+    /// with wrapping arithmetic, in place.
+    ///
+    /// # Example:
+    /// ```
+    /// use hex_str::HexStringN;
+    ///
+    /// let mut v = HexStringN::new([0x00, 0x2b]);
+    /// v.wrapping_sub_bytes_inplace(&HexStringN::new([0x01, 0x01]));
+    /// assert_eq!(v, "ff2a");
+    /// ```
+    pub fn wrapping_sub_bytes_inplace(&mut self, other: &HexStringN<N>) {
+        self.zip_with_inplace(other, u8::wrapping_sub);
+    }
+
+    /// Return a new `HexStringN` with every byte of `other` added to the
+    /// corresponding byte of `self`, with saturating arithmetic.
+    ///
+    /// # Example:
+    /// ```
+    /// use hex_str::HexStringN;
+    ///
+    /// let v = HexStringN::new([0xff, 0x2b]);
+    /// assert_eq!(v.saturating_add_bytes(&HexStringN::new([0x01, 0x01])), "ff2c");
+    /// ```
+    #[must_use]
+    pub fn saturating_add_bytes(&self, other: &HexStringN<N>) -> HexStringN<N> {
+        self.zip_with(other, u8::saturating_add)
+    }
+
+    /// Return a new `HexStringN` with every byte of `other` subtracted from
+    /// the corresponding byte of `self`, with saturating arithmetic.
+    ///
+    /// # Example:
+    /// ```
+    /// use hex_str::HexStringN;
+    ///
+    /// let v = HexStringN::new([0x00, 0x2b]);
+    /// assert_eq!(v.saturating_sub_bytes(&HexStringN::new([0x01, 0x01])), "002a");
+    /// ```
+    #[must_use]
+    pub fn saturating_sub_bytes(&self, other: &HexStringN<N>) -> HexStringN<N> {
+        self.zip_with(other, u8::saturating_sub)
+    }
+
+    /// Return the `n`-th bit of `self`, where bit `0` is the most
+    /// significant bit of the first byte.
+    ///
+    /// # Panics
+    /// - if `n >= 8 * N`
+    ///
+    /// # Example:
+    /// ```
+    /// use hex_str::HexStringN;
+    ///
+    /// let v = HexStringN::new([0x80, 0x01]);
+    /// assert!(v.get_bit(0));
+    /// assert!(!v.get_bit(1));
+    /// assert!(v.get_bit(15));
+    /// ```
+    #[must_use]
+    pub fn get_bit(&self, n: usize) -> bool {
+        let (i, mask) = bit_position::<N>(n);
+        self.0[i] & mask != 0
+    }
+
+    /// Set the `n`-th bit of `self` to `value`, where bit `0` is the most
+    /// significant bit of the first byte.
+    ///
+    /// # Panics
+    /// - if `n >= 8 * N`
+    ///
+    /// # Example:
+    /// ```
+    /// use hex_str::HexStringN;
+    ///
+    /// let mut v = HexStringN::<2>::zeroed();
+    /// v.set_bit(0, true);
+    /// v.set_bit(15, true);
+    /// assert_eq!(v, "8001");
+    /// v.set_bit(0, false);
+    /// assert_eq!(v, "0001");
+    /// ```
+    pub fn set_bit(&mut self, n: usize, value: bool) {
+        let (i, mask) = bit_position::<N>(n);
+        if value {
+            self.0[i] |= mask;
+        } else {
+            self.0[i] &= !mask;
+        }
+    }
+
+    /// Toggle the `n`-th bit of `self`, where bit `0` is the most
+    /// significant bit of the first byte.
+    ///
+    /// # Panics
+    /// - if `n >= 8 * N`
+    ///
+    /// # Example:
+    /// ```
+    /// use hex_str::HexStringN;
+    ///
+    /// let mut v = HexStringN::new([0x0f]);
+    /// v.flip_bit(0);
+    /// v.flip_bit(7);
+    /// assert_eq!(v, "8e");
+    /// ```
+    pub fn flip_bit(&mut self, n: usize) {
+        let (i, mask) = bit_position::<N>(n);
+        self.0[i] ^= mask;
+    }
+}
+
+macro_rules! impl_int_conversions {
+    ($n:literal, $int:ty, $from_be:ident, $to_be:ident, $from_le:ident, $to_le:ident) => {
+        impl HexStringN<$n> {
+            #[doc = concat!("Create a new `HexStringN` from a `", stringify!($int), "`, big-endian.")]
+            ///
+            /// # Example:
+            /// ```
+            /// use hex_str::HexStringN;
+            ///
+            #[doc = concat!("let v = HexStringN::<", stringify!($n), ">::", stringify!($from_be), "(0x1a);")]
+            #[doc = concat!("assert_eq!(v[", stringify!($n), " - 1], 0x1a);")]
+            /// ```
+            #[must_use]
+            pub fn $from_be(v: $int) -> Self {
+                Self::new(v.to_be_bytes())
+            }
+
+            #[doc = concat!("Convert `self` to a `", stringify!($int), "`, big-endian.")]
+            ///
+            /// # Example:
+            /// ```
+            /// use hex_str::HexStringN;
+            ///
+            #[doc = concat!("let v = HexStringN::<", stringify!($n), ">::", stringify!($from_be), "(0x1a);")]
+            #[doc = concat!("assert_eq!(v.", stringify!($to_be), "(), 0x1a);")]
+            /// ```
+            #[must_use]
+            pub fn $to_be(&self) -> $int {
+                <$int>::from_be_bytes(*self.0)
+            }
+
+            #[doc = concat!("Create a new `HexStringN` from a `", stringify!($int), "`, little-endian.")]
+            ///
+            /// # Example:
+            /// ```
+            /// use hex_str::HexStringN;
+            ///
+            #[doc = concat!("let v = HexStringN::<", stringify!($n), ">::", stringify!($from_le), "(0x1a);")]
+            /// assert_eq!(v[0], 0x1a);
+            /// ```
+            #[must_use]
+            pub fn $from_le(v: $int) -> Self {
+                Self::new(v.to_le_bytes())
+            }
+
+            #[doc = concat!("Convert `self` to a `", stringify!($int), "`, little-endian.")]
+            ///
+            /// # Example:
+            /// ```
+            /// use hex_str::HexStringN;
+            ///
+            #[doc = concat!("let v = HexStringN::<", stringify!($n), ">::", stringify!($from_le), "(0x1a);")]
+            #[doc = concat!("assert_eq!(v.", stringify!($to_le), "(), 0x1a);")]
+            /// ```
+            #[must_use]
+            pub fn $to_le(&self) -> $int {
+                <$int>::from_le_bytes(*self.0)
+            }
+        }
+
+        impl From<$int> for HexStringN<$n> {
+            fn from(value: $int) -> Self {
+                Self::$from_be(value)
+            }
+        }
+
+        impl From<HexStringN<$n>> for $int {
+            fn from(value: HexStringN<$n>) -> Self {
+                value.$to_be()
+            }
+        }
+    };
+}
+
+impl_int_conversions!(1, u8, from_u8_be, to_u8_be, from_u8_le, to_u8_le);
+impl_int_conversions!(2, u16, from_u16_be, to_u16_be, from_u16_le, to_u16_le);
+impl_int_conversions!(4, u32, from_u32_be, to_u32_be, from_u32_le, to_u32_le);
+impl_int_conversions!(8, u64, from_u64_be, to_u64_be, from_u64_le, to_u64_le);
+impl_int_conversions!(16, u128, from_u128_be, to_u128_be, from_u128_le, to_u128_le);
+
+fn try_parse<const N: usize>(
+    bytes: impl AsRef<[u8]>,
+    conversion_fn: impl Fn(u8, u8) -> Option<u8>,
+) -> Result<HexStringN<N>, HexStringNError> {
+    let bytes = bytes.as_ref();
+    if bytes.len() % 2 != 0 || bytes.len() / 2 != N {
+        return Err(HexStringNError::InvalidLength {
+            expected: N * 2,
+            encountered: bytes.len(),
+        });
+    }
+
+    let mut ret: Box<[MaybeUninit<u8>; N]> = unsafe { Box::new_uninit().assume_init() };
+    let mut i = 0;
+    let mut j = 1;
+    for v in &mut *ret {
+        let a = unsafe { *bytes.get_unchecked(i) };
+        let b = unsafe { *bytes.get_unchecked(j) };
+        conversion_fn(a, b)
+            .ok_or(HexStringNError::InvalidByte {
+                msb: a,
+                lsb: b,
+                index: i,
+            })
+            .map(|w| v.write(w))?;
+
+        // if len == usize::MAX, this will overflow after the last iteration
+        // which is fine
+        i = i.wrapping_add(2);
+        j = j.wrapping_add(2);
+    }
+
+    let ret: Box<[u8; N]> = unsafe { core::mem::transmute(ret) };
+    Ok(HexStringN::new(ret))
+}
+
+fn overwrite_from_hex<const N: usize>(
+    dst: &mut [u8; N],
+    bytes: impl AsRef<[u8]>,
+    conversion_fn: impl Fn(u8, u8) -> Option<u8>,
+) -> Result<(), HexStringNError> {
+    let bytes = bytes.as_ref();
+    if bytes.len() % 2 != 0 || bytes.len() / 2 != N {
+        return Err(HexStringNError::InvalidLength {
+            expected: N * 2,
+            encountered: bytes.len(),
+        });
+    }
+
+    // validate everything first, so that `dst` is left untouched on failure
+    for (i, pair) in bytes.chunks_exact(2).enumerate() {
+        let (a, b) = (pair[0], pair[1]);
+        if conversion_fn(a, b).is_none() {
+            return Err(HexStringNError::InvalidByte {
+                msb: a,
+                lsb: b,
+                index: i * 2,
+            });
+        }
+    }
+
+    for (v, pair) in dst.iter_mut().zip(bytes.chunks_exact(2)) {
+        *v = conversion_fn(pair[0], pair[1]).unwrap_or_else(|| unreachable!());
+    }
+
+    Ok(())
+}
+
+fn try_parse_prefixed<const N: usize>(
+    bytes: impl AsRef<[u8]>,
+    conversion_fn: impl Fn(u8, u8) -> Option<u8>,
+) -> Result<HexStringN<N>, HexStringNError> {
+    let [b'0', b'x' | b'X', bytes @ ..] = bytes.as_ref() else {
+        return Err(HexStringNError::MissingPrefix);
+    };
+
+    try_parse(bytes, conversion_fn).map_err(|err| match err {
+        HexStringNError::InvalidLength {
+            expected,
+            encountered,
+        } => HexStringNError::InvalidLength {
+            expected: expected + 2,
+            encountered: encountered + 2,
+        },
+        HexStringNError::InvalidByte { msb, lsb, index } => HexStringNError::InvalidByte {
+            msb,
+            lsb,
+            index: index + 2,
+        },
+        HexStringNError::UnexpectedSeparator { position } => HexStringNError::UnexpectedSeparator {
+            position: position + 2,
+        },
+        HexStringNError::MissingSeparator { position } => HexStringNError::MissingSeparator {
+            position: position + 2,
+        },
+        err @ HexStringNError::MissingPrefix => err,
+    })
+}
+
+/// Index of the byte containing bit `n`, and the mask selecting it
+fn bit_position<const N: usize>(n: usize) -> (usize, u8) {
+    assert!(
+        n / 8 < N,
+        "bit index out of bounds: the len is {} but the index is {n}",
+        N * 8
+    );
+
+    (n / 8, 0x80 >> (n % 8))
+}
+
+impl<const N: usize> Display for HexStringN<N> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if f.width().is_none() && f.precision().is_none() {
+            self.write_lower_to(f)
+        } else {
+            f.pad(&self.to_lower())
+        }
+    }
+}
+
+impl<const N: usize> LowerHex for HexStringN<N> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.pad_integral(true, "0x", &self.to_lower())
+    }
+}
+
+impl<const N: usize> UpperHex for HexStringN<N> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.pad_integral(true, "0x", &self.to_upper())
+    }
+}
+
+impl<const N: usize> Debug for HexStringN<N> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("HexStringN")
+            .field("n", &N)
+            .field("inner", &format_args!("\"{self}\""))
+            .finish()
+    }
+}
+
+impl<const N: usize> Default for HexStringN<N> {
+    fn default() -> Self {
+        Self::zeroed()
+    }
+}
+
+impl<const N: usize> FromStr for HexStringN<N> {
+    type Err = HexStringNError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_parse(s)
+    }
+}
+
+impl<const N: usize> From<[u8; N]> for HexStringN<N> {
+    fn from(value: [u8; N]) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<const N: usize> From<Box<[u8; N]>> for HexStringN<N> {
+    fn from(value: Box<[u8; N]>) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<const N: usize> From<HexStringN<N>> for Box<[u8; N]> {
+    fn from(value: HexStringN<N>) -> Self {
+        value.0
+    }
+}
+
+impl<'a, const N: usize> TryFrom<&'a str> for HexStringN<N> {
+    type Error = HexStringNError;
+
+    fn try_from(value: &'a str) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl<const N: usize> TryFrom<String> for HexStringN<N> {
+    type Error = HexStringNError;
 
     fn try_from(value: String) -> Result<Self, Self::Error> {
         value.parse()
     }
-}
+}
+
+impl<const N: usize> TryFrom<&[u8]> for HexStringN<N> {
+    type Error = LengthError;
+
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        value.to_vec().try_into()
+    }
+}
+
+impl<const N: usize> TryFrom<HexString> for HexStringN<N> {
+    type Error = HexString;
+
+    fn try_from(value: HexString) -> Result<Self, Self::Error> {
+        if value.len() != N {
+            return Err(value);
+        }
+
+        let ret = Box::<[u8; N]>::try_from(Vec::from(value).into_boxed_slice())
+            .unwrap_or_else(|_| unreachable!());
+        Ok(Self::new(ret))
+    }
+}
+
+impl<const N: usize> TryFrom<Vec<u8>> for HexStringN<N> {
+    type Error = LengthError;
+
+    fn try_from(value: Vec<u8>) -> Result<Self, Self::Error> {
+        let encountered = value.len();
+        Box::<[u8; N]>::try_from(value.into_boxed_slice())
+            .map(Self::new)
+            .map_err(|_| LengthError {
+                expected: N,
+                encountered,
+            })
+    }
+}
+
+impl<const N: usize> PartialEq<[u8; N]> for HexStringN<N> {
+    fn eq(&self, other: &[u8; N]) -> bool {
+        &*self.0 == other
+    }
+}
+
+impl<const N: usize> PartialEq<&[u8; N]> for HexStringN<N> {
+    fn eq(&self, other: &&[u8; N]) -> bool {
+        &*self.0 == *other
+    }
+}
+
+impl<const N: usize> PartialEq<[u8]> for HexStringN<N> {
+    fn eq(&self, other: &[u8]) -> bool {
+        *self.0 == other
+    }
+}
+
+impl<const N: usize> PartialEq<&[u8]> for HexStringN<N> {
+    fn eq(&self, other: &&[u8]) -> bool {
+        *self.0 == *other
+    }
+}
+
+impl<const N: usize> PartialEq<str> for HexStringN<N> {
+    #[allow(clippy::many_single_char_names)]
+    fn eq(&self, other: &str) -> bool {
+        if other.len() % 2 == 0 && other.len() / 2 == self.0.len() {
+            let bytes = other.as_bytes();
+            let mut i = 0;
+            let mut j = 1;
+            for x in &*self.0 {
+                let a = unsafe { *bytes.get_unchecked(i) };
+                let b = unsafe { *bytes.get_unchecked(j) };
+
+                let Some(y) = utils::parse(a, b) else {
+                    return false;
+                };
+
+                if *x != y {
+                    return false;
+                }
+
+                // if len == usize::MAX, this will overflow after the last iteration
+                // which is fine
+                i = i.wrapping_add(2);
+                j = j.wrapping_add(2);
+            }
+
+            true
+        } else {
+            false
+        }
+    }
+}
+
+impl<const N: usize> PartialEq<&str> for HexStringN<N> {
+    fn eq(&self, other: &&str) -> bool {
+        self == *other
+    }
+}
+
+impl<const N: usize> PartialEq<String> for HexStringN<N> {
+    fn eq(&self, other: &String) -> bool {
+        self == other.as_str()
+    }
+}
+
+impl<const N: usize> PartialEq<HexString> for HexStringN<N> {
+    fn eq(&self, other: &HexString) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<const N: usize> PartialOrd<HexString> for HexStringN<N> {
+    fn partial_cmp(&self, other: &HexString) -> Option<Ordering> {
+        Some(self.as_slice().cmp(other.as_slice()))
+    }
+}
+
+macro_rules! impl_bit_op {
+    ($op:ident, $op_fn:ident, $op_assign:ident, $op_assign_fn:ident, $op_token:tt) => {
+        impl<const N: usize> $op_assign<&[u8; N]> for HexStringN<N> {
+            fn $op_assign_fn(&mut self, rhs: &[u8; N]) {
+                for (a, b) in self.0.iter_mut().zip(rhs) {
+                    *a $op_token *b;
+                }
+            }
+        }
+
+        impl<const N: usize> $op_assign<&HexStringN<N>> for HexStringN<N> {
+            fn $op_assign_fn(&mut self, rhs: &HexStringN<N>) {
+                self.$op_assign_fn(rhs.as_array());
+            }
+        }
+
+        impl<const N: usize> $op_assign<HexStringN<N>> for HexStringN<N> {
+            fn $op_assign_fn(&mut self, rhs: HexStringN<N>) {
+                self.$op_assign_fn(rhs.as_array());
+            }
+        }
+
+        impl<const N: usize> $op<&[u8; N]> for HexStringN<N> {
+            type Output = HexStringN<N>;
+
+            fn $op_fn(mut self, rhs: &[u8; N]) -> Self::Output {
+                self.$op_assign_fn(rhs);
+                self
+            }
+        }
+
+        impl<const N: usize> $op<&HexStringN<N>> for HexStringN<N> {
+            type Output = HexStringN<N>;
+
+            fn $op_fn(self, rhs: &HexStringN<N>) -> Self::Output {
+                self.$op_fn(rhs.as_array())
+            }
+        }
+
+        impl<const N: usize> $op<HexStringN<N>> for HexStringN<N> {
+            type Output = HexStringN<N>;
+
+            fn $op_fn(self, rhs: HexStringN<N>) -> Self::Output {
+                self.$op_fn(rhs.as_array())
+            }
+        }
+
+        impl<const N: usize> $op<&[u8; N]> for &HexStringN<N> {
+            type Output = HexStringN<N>;
+
+            fn $op_fn(self, rhs: &[u8; N]) -> Self::Output {
+                self.clone().$op_fn(rhs)
+            }
+        }
+
+        impl<const N: usize> $op<&HexStringN<N>> for &HexStringN<N> {
+            type Output = HexStringN<N>;
+
+            fn $op_fn(self, rhs: &HexStringN<N>) -> Self::Output {
+                self.clone().$op_fn(rhs.as_array())
+            }
+        }
+    };
+}
+
+impl_bit_op!(BitXor, bitxor, BitXorAssign, bitxor_assign, ^=);
+impl_bit_op!(BitAnd, bitand, BitAndAssign, bitand_assign, &=);
+impl_bit_op!(BitOr, bitor, BitOrAssign, bitor_assign, |=);
+
+impl<const N: usize> Not for HexStringN<N> {
+    type Output = HexStringN<N>;
+
+    fn not(mut self) -> Self::Output {
+        for v in &mut *self.0 {
+            *v = !*v;
+        }
+
+        self
+    }
+}
+
+impl<const N: usize> Not for &HexStringN<N> {
+    type Output = HexStringN<N>;
+
+    fn not(self) -> Self::Output {
+        !self.clone()
+    }
+}
+
+impl<const N: usize> IntoIterator for HexStringN<N> {
+    type Item = u8;
+    type IntoIter = alloc::vec::IntoIter<u8>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.into_vec().into_iter()
+    }
+}
+
+impl<'a, const N: usize> IntoIterator for &'a HexStringN<N> {
+    type Item = &'a u8;
+    type IntoIter = core::slice::Iter<'a, u8>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, const N: usize> IntoIterator for &'a mut HexStringN<N> {
+    type Item = &'a mut u8;
+    type IntoIter = core::slice::IterMut<'a, u8>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl<const N: usize> Deref for HexStringN<N> {
+    type Target = [u8; N];
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<const N: usize> DerefMut for HexStringN<N> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<const N: usize> AsRef<HexStringN<N>> for HexStringN<N> {
+    fn as_ref(&self) -> &HexStringN<N> {
+        self
+    }
+}
+
+impl<const N: usize> AsMut<HexStringN<N>> for HexStringN<N> {
+    fn as_mut(&mut self) -> &mut HexStringN<N> {
+        self
+    }
+}
+
+impl<const N: usize> AsRef<[u8; N]> for HexStringN<N> {
+    fn as_ref(&self) -> &[u8; N] {
+        &self.0
+    }
+}
+
+impl<const N: usize> AsMut<[u8; N]> for HexStringN<N> {
+    fn as_mut(&mut self) -> &mut [u8; N] {
+        &mut self.0
+    }
+}
+
+impl<const N: usize> AsRef<[u8]> for HexStringN<N> {
+    fn as_ref(&self) -> &[u8] {
+        &*self.0
+    }
+}
+
+impl<const N: usize> AsMut<[u8]> for HexStringN<N> {
+    fn as_mut(&mut self) -> &mut [u8] {
+        &mut *self.0
+    }
+}
+
+impl<const N: usize> Borrow<[u8; N]> for HexStringN<N> {
+    fn borrow(&self) -> &[u8; N] {
+        self
+    }
+}
+
+impl<const N: usize> BorrowMut<[u8; N]> for HexStringN<N> {
+    fn borrow_mut(&mut self) -> &mut [u8; N] {
+        self
+    }
+}
+
+impl<const N: usize> Borrow<[u8]> for HexStringN<N> {
+    fn borrow(&self) -> &[u8] {
+        self.as_slice()
+    }
+}
+
+impl<const N: usize> BorrowMut<[u8]> for HexStringN<N> {
+    fn borrow_mut(&mut self) -> &mut [u8] {
+        self.as_mut_slice()
+    }
+}
+
+impl<const N: usize> Borrow<HexStringN<N>> for Box<[u8; N]> {
+    fn borrow(&self) -> &HexStringN<N> {
+        // Safety: HexString is #[repr(transparent)]
+        unsafe { &*ptr::from_ref(self).cast() }
+    }
+}
+
+impl<const N: usize> BorrowMut<HexStringN<N>> for Box<[u8; N]> {
+    fn borrow_mut(&mut self) -> &mut HexStringN<N> {
+        // Safety: HexString is #[repr(transparent)]
+        unsafe { &mut *ptr::from_mut(self).cast() }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, const N: usize> serde::Deserialize<'de> for HexStringN<N> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct Visitor<const O: usize>;
+
+        impl<'de, const O: usize> serde::de::Visitor<'de> for Visitor<O> {
+            type Value = HexStringN<O>;
+
+            fn expecting(&self, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                formatter.write_fmt(format_args!("hex string or bytes of length `{O}`"))
+            }
+
+            fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                v.parse().map_err(|err| E::custom(err))
+            }
+
+            fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                match v.len() {
+                    // versions before 0.4 stored hex strings in binary formats
+                    len if len == 2 * O => HexStringN::try_parse(v).map_err(|err| E::custom(err)),
+                    len => HexStringN::try_from(v).map_err(|_| E::invalid_length(len, &self)),
+                }
+            }
+
+            fn visit_byte_buf<E>(self, v: Vec<u8>) -> Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                if v.len() == 2 * O {
+                    return self.visit_bytes(&v);
+                }
+
+                let len = v.len();
+                HexStringN::try_from(v).map_err(|_| E::invalid_length(len, &self))
+            }
+        }
+
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(Visitor)
+        } else {
+            deserializer.deserialize_bytes(Visitor)
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<const N: usize> serde::Serialize for HexStringN<N> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        if serializer.is_human_readable() {
+            serializer.collect_str(self)
+        } else {
+            serializer.serialize_bytes(&self.0[..])
+        }
+    }
+}
+
+#[cfg(feature = "rand")]
+impl<const N: usize> rand::distributions::Distribution<HexStringN<N>>
+    for rand::distributions::Standard
+{
+    fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> HexStringN<N> {
+        let mut ret: Box<[MaybeUninit<u8>; N]> = unsafe { Box::new_uninit().assume_init() };
+        for v in &mut *ret {
+            v.write(rng.gen());
+        }
+
+        let ret: Box<[u8; N]> = unsafe { core::mem::transmute(ret) };
+        HexStringN::new(ret)
+    }
+}
+
+#[cfg(feature = "uuid")]
+impl HexStringN<16> {
+    /// Try to parse a UUID in its canonical `8-4-4-4-12` form, such as
+    /// `67e55044-10b1-426f-9247-bb680e5fe0c8`, both lowercase and uppercase
+    /// characters allowed.
+    ///
+    /// # Errors
+    /// - if `s.len() != 36`
+    /// - if a `-` is missing from, or found outside of, its position
+    /// - if `s` contains characters other than `[0-9a-fA-F]` outside of the
+    ///   separators
+    ///
+    /// # Example:
+    /// ```
+    /// use hex_str::{HexStringN, HexStringNError};
+    ///
+    /// let v = HexStringN::from_uuid_str("67e55044-10b1-426f-9247-BB680E5FE0C8").unwrap();
+    /// assert_eq!(v, "67e5504410b1426f9247bb680e5fe0c8");
+    ///
+    /// let err = HexStringN::from_uuid_str("67e5504-410b1-426f-9247-bb680e5fe0c8").unwrap_err();
+    /// assert_eq!(err, HexStringNError::UnexpectedSeparator { position: 7 });
+    /// ```
+    pub fn from_uuid_str(s: &str) -> Result<Self, HexStringNError> {
+        const SEPARATORS: [usize; 4] = [8, 13, 18, 23];
+
+        let s = s.as_bytes();
+        if s.len() != 36 {
+            return Err(HexStringNError::InvalidLength {
+                expected: 36,
+                encountered: s.len(),
+            });
+        }
+
+        let mut hex = [0; 32];
+        let mut len = 0;
+        for (position, v) in s.iter().copied().enumerate() {
+            match (SEPARATORS.contains(&position), v == b'-') {
+                (true, true) => {}
+                (true, false) => return Err(HexStringNError::MissingSeparator { position }),
+                (false, true) => return Err(HexStringNError::UnexpectedSeparator { position }),
+                (false, false) => {
+                    hex[len] = v;
+                    len += 1;
+                }
+            }
+        }
+
+        Self::try_parse(hex).map_err(|err| match err {
+            // map the index back to `s`, skipping over the separators before it
+            HexStringNError::InvalidByte { msb, lsb, index } => HexStringNError::InvalidByte {
+                msb,
+                lsb,
+                index: index + [8, 12, 16, 20].iter().filter(|v| **v <= index).count(),
+            },
+            err => err,
+        })
+    }
+}
+
+#[cfg(feature = "uuid")]
+impl From<HexStringN<16>> for uuid::Uuid {
+    fn from(value: HexStringN<16>) -> Self {
+        uuid::Uuid::from_bytes(*value.0)
+    }
+}
+
+#[cfg(feature = "uuid")]
+impl From<uuid::Uuid> for HexStringN<16> {
+    fn from(value: uuid::Uuid) -> Self {
+        HexStringN::new(value.into_bytes())
+    }
+}
+
+#[cfg(feature = "subtle")]
+impl<const N: usize> subtle::ConstantTimeEq for HexStringN<N> {
+    fn ct_eq(&self, other: &Self) -> subtle::Choice {
+        self.0[..].ct_eq(&other.0[..])
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<const N: usize> zeroize::Zeroize for HexStringN<N> {
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+#[cfg(feature = "sqlx")]
+impl<DB: sqlx::Database, const N: usize> sqlx::Type<DB> for HexStringN<N>
+where
+    Vec<u8>: sqlx::Type<DB>,
+{
+    fn type_info() -> DB::TypeInfo {
+        <Vec<u8> as sqlx::Type<DB>>::type_info()
+    }
+
+    fn compatible(ty: &DB::TypeInfo) -> bool {
+        <Vec<u8> as sqlx::Type<DB>>::compatible(ty)
+    }
+}
+
+#[cfg(feature = "sqlx")]
+impl<'q, DB: sqlx::Database, const N: usize> sqlx::Encode<'q, DB> for HexStringN<N>
+where
+    Vec<u8>: sqlx::Encode<'q, DB>,
+{
+    fn encode_by_ref(
+        &self,
+        buf: &mut DB::ArgumentBuffer<'q>,
+    ) -> Result<sqlx::encode::IsNull, sqlx::error::BoxDynError> {
+        self.0.to_vec().encode(buf)
+    }
+}
+
+#[cfg(feature = "sqlx")]
+impl<'r, DB: sqlx::Database, const N: usize> sqlx::Decode<'r, DB> for HexStringN<N>
+where
+    Vec<u8>: sqlx::Decode<'r, DB>,
+{
+    fn decode(value: DB::ValueRef<'r>) -> Result<Self, sqlx::error::BoxDynError> {
+        let v = <Vec<u8> as sqlx::Decode<'r, DB>>::decode(value)?;
+        Ok(Self::try_from(v)?)
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a, const N: usize> arbitrary::Arbitrary<'a> for HexStringN<N> {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let mut ret = Self::zeroed();
+        u.fill_buffer(&mut ret.0[..])?;
+
+        Ok(ret)
+    }
+
+    fn size_hint(_depth: usize) -> (usize, Option<usize>) {
+        (N, Some(N))
+    }
+}
+
+#[cfg(feature = "proptest")]
+impl<const N: usize> proptest::arbitrary::Arbitrary for HexStringN<N> {
+    type Parameters = ();
+    type Strategy = proptest::strategy::Map<
+        proptest::array::UniformArrayStrategy<proptest::num::u8::Any, [u8; N]>,
+        fn([u8; N]) -> Self,
+    >;
+
+    fn arbitrary_with((): Self::Parameters) -> Self::Strategy {
+        use proptest::strategy::Strategy;
+
+        proptest::array::uniform(proptest::num::u8::ANY).prop_map(HexStringN::new)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "rand")]
+    use rand::seq::SliceRandom;
+
+    use super::{HexString, HexStringN, HexStringNError, LengthError};
+
+    #[test]
+    fn ct_eq() {
+        let a = HexStringN::new([0x1a, 0x2b, 0x3c, 0x4d]);
+        let values = [
+            [0x1a, 0x2b, 0x3c, 0x4d],
+            [0x1b, 0x2b, 0x3c, 0x4d],
+            [0x1a, 0x2b, 0x3c, 0x4c],
+            [0x00, 0x00, 0x00, 0x00],
+            [0xff, 0xff, 0xff, 0xff],
+        ];
+
+        for v in values {
+            let b = HexStringN::new(v);
+            assert_eq!(a.ct_eq_bool(&b), a == b);
+
+            #[cfg(feature = "subtle")]
+            {
+                use subtle::ConstantTimeEq;
+
+                assert_eq!(bool::from(a.ct_eq(&b)), a == b);
+            }
+        }
+
+        assert!(HexStringN::new([]).ct_eq_bool(&HexStringN::new([])));
+    }
+
+    #[cfg(feature = "uuid")]
+    #[test]
+    fn uuid() {
+        let s = "67e55044-10b1-426f-9247-bb680e5fe0c8";
+        let u = uuid::Uuid::parse_str(s).unwrap();
+
+        let v = HexStringN::from_uuid_str(s).unwrap();
+        assert_eq!(v, *u.as_bytes());
+        assert_eq!(uuid::Uuid::from(v.clone()), u);
+        assert_eq!(HexStringN::from(u), v);
+        assert_eq!(HexStringN::from_uuid_str(&s.to_uppercase()).unwrap(), v);
+
+        assert_eq!(
+            HexStringN::from_uuid_str("67e5504410b1426f9247bb680e5fe0c8"),
+            Err(HexStringNError::InvalidLength {
+                expected: 36,
+                encountered: 32
+            })
+        );
+        assert_eq!(
+            HexStringN::from_uuid_str("67e55044-10b1-426f-9247bb680e-5fe0c8"),
+            Err(HexStringNError::MissingSeparator { position: 23 })
+        );
+        assert_eq!(
+            HexStringN::from_uuid_str("67e55044-10b1-426f--247-bb680e5fe0c8"),
+            Err(HexStringNError::UnexpectedSeparator { position: 19 })
+        );
+
+        // indices point into the original string
+        for (i, position) in [(0, 0), (7, 6), (9, 9), (35, 34), (24, 24), (22, 21)] {
+            let mut bad = s.as_bytes().to_vec();
+            bad[i] = b'g';
+            let bad = String::from_utf8(bad).unwrap();
+            let Err(HexStringNError::InvalidByte { index, .. }) = HexStringN::from_uuid_str(&bad)
+            else {
+                panic!();
+            };
+            assert_eq!(index, position, "{bad}");
+        }
+    }
+
+    #[cfg(feature = "sqlx")]
+    #[tokio::test]
+    async fn sqlx() {
+        let pool = sqlx::SqlitePool::connect("sqlite::memory:").await.unwrap();
+        sqlx::query("CREATE TABLE t (v BLOB NOT NULL)")
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        let v = HexStringN::new([0xde, 0xad, 0xbe, 0xef]);
+        sqlx::query("INSERT INTO t (v) VALUES (?)")
+            .bind(&v)
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        let (ret,): (HexStringN<4>,) = sqlx::query_as("SELECT v FROM t")
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(ret, v);
+
+        let err = sqlx::query_as::<_, (HexStringN<3>,)>("SELECT v FROM t")
+            .fetch_one(&pool)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, sqlx::Error::ColumnDecode { .. }), "{err}");
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_tokens() {
+        use serde_test::{
+            assert_de_tokens, assert_de_tokens_error, assert_tokens, Configure, Token,
+        };
+
+        let v = HexStringN::new([0x1a, 0x2b]);
+
+        assert_tokens(&v.clone().readable(), &[Token::Str("1a2b")]);
+        assert_tokens(&v.clone().compact(), &[Token::Bytes(&[0x1a, 0x2b])]);
+        assert_de_tokens(&v.clone().compact(), &[Token::ByteBuf(&[0x1a, 0x2b])]);
+        // strings are accepted by binary formats too
+        assert_de_tokens(&v.clone().compact(), &[Token::Str("1A2B")]);
+
+        assert_de_tokens_error::<serde_test::Compact<HexStringN<2>>>(
+            &[Token::Bytes(&[0x1a])],
+            "invalid length 1, expected hex string or bytes of length `2`",
+        );
+
+        // hex strings stored as bytes by versions before 0.4
+        assert_de_tokens(&v.clone().compact(), &[Token::Bytes(b"1a2B")]);
+        assert_de_tokens(&v.compact(), &[Token::ByteBuf(b"1a2b")]);
+        assert_de_tokens_error::<serde_test::Compact<HexStringN<2>>>(
+            &[Token::Bytes(b"1a2g")],
+            "invalid characters `2g` encountered at index 2",
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_formats() {
+        let v = HexStringN::new([0xde, 0xad, 0xbe, 0xef]);
+
+        let json = serde_json::to_string(&v).unwrap();
+        assert_eq!(json, r#""deadbeef""#);
+        assert_eq!(serde_json::from_str::<HexStringN<4>>(&json).unwrap(), v);
+
+        let bin = bincode::serialize(&v).unwrap();
+        assert_eq!(bin, [4, 0, 0, 0, 0, 0, 0, 0, 0xde, 0xad, 0xbe, 0xef]);
+        assert_eq!(bincode::deserialize::<HexStringN<4>>(&bin).unwrap(), v);
+        assert!(bincode::deserialize::<HexStringN<3>>(&bin).is_err());
+
+        // written by versions before 0.4
+        let legacy = bincode::serialize("deadbeef").unwrap();
+        assert_eq!(bincode::deserialize::<HexStringN<4>>(&legacy).unwrap(), v);
+    }
+
+    #[cfg(feature = "arbitrary")]
+    #[test]
+    fn arbitrary() {
+        use arbitrary::{Arbitrary, Unstructured};
+
+        let data: Vec<u8> = (0..=u8::MAX).collect();
+        let mut u = Unstructured::new(&data);
+
+        let v = HexStringN::<4>::arbitrary(&mut u).unwrap();
+        assert_eq!(v, [0x00, 0x01, 0x02, 0x03]);
+        assert_eq!(u.len(), 252);
+
+        let v = HexStringN::<0>::arbitrary(&mut u).unwrap();
+        assert_eq!(v, "");
+        assert_eq!(u.len(), 252);
+
+        assert_eq!(HexStringN::<4>::size_hint(0), (4, Some(4)));
+    }
+
+    #[cfg(feature = "proptest")]
+    proptest::proptest! {
+        #[test]
+        fn proptest_round_trip(v: HexStringN<16>) {
+            proptest::prop_assert_eq!(HexStringN::<16>::try_parse(v.to_lower()).unwrap(), v.clone());
+            proptest::prop_assert_eq!(HexStringN::<16>::try_parse(v.to_upper()).unwrap(), v);
+        }
+    }
+
+    #[cfg(feature = "proptest")]
+    #[test]
+    fn proptest_shrink() {
+        use proptest::{
+            arbitrary::any,
+            strategy::{Strategy, ValueTree},
+            test_runner::TestRunner,
+        };
+
+        let mut runner = TestRunner::deterministic();
+        let mut tree = any::<HexStringN<8>>().new_tree(&mut runner).unwrap();
+        while tree.simplify() {}
+
+        assert_eq!(tree.current(), HexStringN::<8>::zeroed());
+    }
+
+    #[cfg(feature = "zeroize")]
+    #[test]
+    fn zeroize() {
+        use zeroize::{Zeroize, ZeroizeOnDrop, Zeroizing};
+
+        #[derive(Zeroize, ZeroizeOnDrop)]
+        struct Key {
+            key: HexStringN<4>,
+            nonce: HexStringN<2>,
+        }
+
+        let mut v = HexStringN::new([0xab; 32]);
+        v.zeroize();
+        assert_eq!(v, HexStringN::new([0; 32]));
+
+        let v = Zeroizing::new(HexStringN::new([0x1a, 0x2b]));
+        assert_eq!(*v, "1a2b");
+
+        let mut key = Key {
+            key: HexStringN::new([0xde, 0xad, 0xbe, 0xef]),
+            nonce: HexStringN::new([0x1a, 0x2b]),
+        };
+        key.zeroize();
+        assert_eq!(key.key, "00000000");
+        assert_eq!(key.nonce, "0000");
+    }
 
-impl<const N: usize> PartialEq<[u8; N]> for HexStringN<N> {
-    fn eq(&self, other: &[u8; N]) -> bool {
-        &*self.0 == other
+    #[cfg(feature = "rand")]
+    #[test]
+    fn big_rand() {
+        // 128 * 1024 + 1
+        let _: HexStringN<131_073> = rand::random();
     }
-}
 
-impl<const N: usize> PartialEq<&[u8; N]> for HexStringN<N> {
-    fn eq(&self, other: &&[u8; N]) -> bool {
-        &*self.0 == *other
+    #[cfg(feature = "rand")]
+    #[test]
+    fn big_hex() {
+        let mut rng = rand::thread_rng();
+        let v = (0..262_146).fold(String::new(), |mut acc, _| {
+            let v = *b"0123456789abcdefABCDEF".choose(&mut rng).unwrap();
+            acc.push(v.into());
+            acc
+        });
+
+        // 128 * 1024 + 1
+        let parsed: HexStringN<131_073> = v.parse().unwrap();
+        assert_eq!(parsed.to_lower(), v.to_lowercase());
     }
-}
 
-impl<const N: usize> PartialEq<[u8]> for HexStringN<N> {
-    fn eq(&self, other: &[u8]) -> bool {
-        *self.0 == other
+    #[test]
+    fn ord() {
+        use std::collections::BTreeSet;
+
+        let a = HexStringN::<16>::new([0x00; 16]);
+        let b = HexStringN::<16>::new([0x1a; 16]);
+        let c = HexStringN::<16>::new([0xff; 16]);
+
+        let set = BTreeSet::from([c.clone(), a.clone(), b.clone()]);
+        assert!(set.contains(&b));
+        assert_eq!(
+            set.into_iter().collect::<Vec<_>>(),
+            [a.clone(), b.clone(), c]
+        );
+        assert_eq!(a.cmp(&b), a.as_slice().cmp(b.as_slice()));
     }
-}
 
-impl<const N: usize> PartialEq<&[u8]> for HexStringN<N> {
-    fn eq(&self, other: &&[u8]) -> bool {
-        *self.0 == *other
+    #[test]
+    fn bit_ops() {
+        let a = HexStringN::new([0b1100_1010, 0xff]);
+        let b = HexStringN::new([0b1010_0110, 0x00]);
+
+        assert_eq!(&a ^ &b, [0b0110_1100, 0xff]);
+        assert_eq!(&a & &b, [0b1000_0010, 0x00]);
+        assert_eq!(&a | &b, [0b1110_1110, 0xff]);
+        assert_eq!(a.clone() ^ &[0xff, 0xff], [0b0011_0101, 0x00]);
+        assert_eq!(&a ^ &a, [0x00; 2]);
+
+        let mut c = a.clone();
+        c ^= &b;
+        assert_eq!(c, &a ^ &b);
+        c &= &[0x0f, 0x0f];
+        assert_eq!(c, [0b0000_1100, 0x0f]);
+        c |= b;
+        assert_eq!(c, [0b1010_1110, 0x0f]);
     }
-}
 
-impl<const N: usize> PartialEq<str> for HexStringN<N> {
-    #[allow(clippy::many_single_char_names)]
-    fn eq(&self, other: &str) -> bool {
-        if other.len() % 2 == 0 && other.len() / 2 == self.0.len() {
-            let bytes = other.as_bytes();
-            let mut i = 0;
-            let mut j = 1;
-            for x in &*self.0 {
-                let a = unsafe { *bytes.get_unchecked(i) };
-                let b = unsafe { *bytes.get_unchecked(j) };
+    #[test]
+    fn bit_xor_vector() {
+        let a: HexStringN<18> = "1c0111001f010100061a024b53535009181c".parse().unwrap();
+        let b: HexStringN<18> = "686974207468652062756c6c277320657965".parse().unwrap();
 
-                let Some(y) = utils::parse(a, b) else {
-                    return false;
-                };
+        assert_eq!(a ^ b, "746865206b696420646f6e277420706c6179");
+    }
 
-                if *x != y {
-                    return false;
-                }
+    #[test]
+    fn not() {
+        let x: HexStringN<4> = "1a2b3c4d".parse().unwrap();
 
-                // if len == usize::MAX, this will overflow after the last iteration
-                // which is fine
-                i = i.wrapping_add(2);
-                j = j.wrapping_add(2);
-            }
+        assert_eq!(!&x, "e5d4c3b2");
+        assert_eq!(!!x.clone(), x);
+        assert_eq!(&x & &!&x, [0x00; 4]);
+        assert_eq!(!HexStringN::new([0x00; 16]), [0xff; 16]);
+    }
 
-            true
-        } else {
-            false
-        }
+    #[test]
+    fn constructors() {
+        assert_eq!(HexStringN::<0>::zeroed(), "");
+        assert_eq!(HexStringN::<3>::zeroed(), [0x00; 3]);
+        assert_eq!(HexStringN::<3>::ones(), [0xff; 3]);
+        assert_eq!(HexStringN::<3>::filled(0xab), [0xab; 3]);
+        assert_eq!(HexStringN::<3>::default(), HexStringN::zeroed());
+
+        // 128 * 1024 + 1
+        let big = HexStringN::<131_073>::filled(0x5a);
+        assert!(big.iter().all(|&v| v == 0x5a));
     }
-}
 
-impl<const N: usize> PartialEq<&str> for HexStringN<N> {
-    fn eq(&self, other: &&str) -> bool {
-        self == *other
+    #[test]
+    fn int_conversions() {
+        assert_eq!(HexStringN::from(0x1a_u8), "1a");
+        assert_eq!(HexStringN::from(0x1a2b_u16), "1a2b");
+        assert_eq!(HexStringN::from(0x1a2b_3c4d_u32), "1a2b3c4d");
+        assert_eq!(HexStringN::from_u32_le(0x1a2b_3c4d), "4d3c2b1a");
+        assert_eq!(
+            HexStringN::from(0x0102_0304_0506_0708_u64),
+            [0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08]
+        );
+        assert_eq!(
+            HexStringN::from_u64_le(0x0102_0304_0506_0708),
+            [0x08, 0x07, 0x06, 0x05, 0x04, 0x03, 0x02, 0x01]
+        );
+        assert_eq!(
+            HexStringN::from(u128::MAX - 1),
+            "fffffffffffffffffffffffffffffffe"
+        );
+
+        let v: HexStringN<8> = "00000000deadbeef".parse().unwrap();
+        assert_eq!(u64::from(v.clone()), 0xdead_beef);
+        assert_eq!(v.to_u64_le(), 0xefbe_adde_0000_0000);
+        assert_eq!(HexStringN::from_u64_le(v.to_u64_le()), v);
+
+        let v: HexStringN<16> = "0102030405060708090a0b0c0d0e0f10".parse().unwrap();
+        assert_eq!(HexStringN::from_u128_be(v.to_u128_be()), v);
+        assert_eq!(HexStringN::from_u128_le(v.to_u128_le()), v);
+        assert_eq!(u8::from(HexStringN::new([0xab])), 0xab);
+        assert_eq!(HexStringN::new([0xab, 0xcd]).to_u16_le(), 0xcdab);
     }
-}
 
-impl<const N: usize> PartialEq<String> for HexStringN<N> {
-    fn eq(&self, other: &String) -> bool {
-        self == other.as_str()
+    #[test]
+    fn reverse() {
+        let mut v = HexStringN::new([0xab]);
+        v.reverse_bytes();
+        assert_eq!(v, [0xab]);
+
+        let v: HexStringN<4> = "deadbeef".parse().unwrap();
+        assert_eq!(v.reversed(), "efbeadde");
+        assert_eq!(v.reversed().reversed(), v);
+        assert_eq!(v.reversed().to_u32_be(), v.to_u32_le());
     }
-}
 
-impl<const N: usize> Deref for HexStringN<N> {
-    type Target = [u8; N];
+    #[test]
+    fn rotate() {
+        let v: HexStringN<4> = "1a2b3c4d".parse().unwrap();
+        assert_eq!(v.rotate_left(0), v);
+        assert_eq!(v.rotate_left(4), v);
+        assert_eq!(v.rotate_right(0), v);
+        assert_eq!(v.rotate_right(4), v);
+        assert_eq!(v.rotate_left(1).rotate_right(1), v);
+        assert_eq!(v.rotate_left(3), v.rotate_right(1));
+
+        let mut v = HexStringN::<0>::zeroed();
+        v.rotate_left_inplace(3);
+        v.rotate_right_inplace(3);
+        assert_eq!(v, "");
+    }
 
-    fn deref(&self) -> &Self::Target {
-        &self.0
+    #[test]
+    fn hamming_distance() {
+        let a = HexStringN::<16>::zeroed();
+        let b = HexStringN::<16>::ones();
+        assert_eq!(a.hamming_distance(&b), 16 * 8);
+        assert_eq!(a.hamming_distance(&a), 0);
+
+        // "this is a test" and "wokka wokka!!!", from cryptopals
+        let a = HexStringN::<14>::new(*b"this is a test");
+        let b = HexStringN::<14>::new(*b"wokka wokka!!!");
+        assert_eq!(a.hamming_distance(&b), 37);
+        assert_eq!(a.hamming_distance_bytes(b.as_slice()), Ok(37));
+        assert_eq!((&a ^ &b).count_ones(), 37);
     }
-}
 
-impl<const N: usize> DerefMut for HexStringN<N> {
-    fn deref_mut(&mut self) -> &mut Self::Target {
-        &mut self.0
+    #[test]
+    fn predicates() {
+        assert!(HexStringN::<16>::zeroed().is_zero());
+        assert!(!HexStringN::<16>::zeroed().is_all_ones());
+        assert!(HexStringN::<16>::ones().is_all_ones());
+        assert!(!HexStringN::<16>::ones().is_zero());
+
+        assert!(!HexStringN::new([0x01, 0x00, 0x00]).is_zero());
+        assert!(!HexStringN::new([0x00, 0x00, 0x01]).is_zero());
+        assert!(!HexStringN::new([0x7f, 0xff, 0xff]).is_all_ones());
+        assert!(!HexStringN::new([0xff, 0xff, 0xfe]).is_all_ones());
+
+        assert!(HexStringN::new([0x00]).is_zero());
+        assert!(HexStringN::new([0xff]).is_all_ones());
+        assert!(!HexStringN::new([0x80]).is_zero());
+        assert!(!HexStringN::new([0x80]).is_all_ones());
     }
-}
 
-impl<const N: usize> AsRef<HexStringN<N>> for HexStringN<N> {
-    fn as_ref(&self) -> &HexStringN<N> {
-        self
+    #[test]
+    fn try_from_bytes() {
+        use crate::LengthError;
+
+        let v = HexStringN::<2>::try_from(&[0x1a, 0x2b][..]).unwrap();
+        assert_eq!(v, [0x1a, 0x2b]);
+        let v = HexStringN::<2>::try_from(vec![0x1a, 0x2b]).unwrap();
+        assert_eq!(v, [0x1a, 0x2b]);
+
+        let err = HexStringN::<2>::try_from(&[0x1a][..]).unwrap_err();
+        assert_eq!(
+            err,
+            LengthError {
+                expected: 2,
+                encountered: 1
+            }
+        );
+        let err = HexStringN::<2>::try_from(&[0x1a, 0x2b, 0x3c][..]).unwrap_err();
+        assert_eq!(
+            err,
+            LengthError {
+                expected: 2,
+                encountered: 3
+            }
+        );
+        let err = HexStringN::<2>::try_from(vec![0x1a]).unwrap_err();
+        assert_eq!(
+            err,
+            LengthError {
+                expected: 2,
+                encountered: 1
+            }
+        );
+        let err = HexStringN::<2>::try_from(vec![0x1a, 0x2b, 0x3c]).unwrap_err();
+        assert_eq!(
+            err,
+            LengthError {
+                expected: 2,
+                encountered: 3
+            }
+        );
     }
-}
 
-impl<const N: usize> AsMut<HexStringN<N>> for HexStringN<N> {
-    fn as_mut(&mut self) -> &mut HexStringN<N> {
-        self
+    #[test]
+    fn try_from_hex_string() {
+        let v = HexString::new([0x1a, 0x2b]);
+        assert_eq!(HexStringN::<2>::try_from(v).unwrap(), [0x1a, 0x2b]);
+
+        let v = HexString::new([0x1a]);
+        assert_eq!(HexStringN::<2>::try_from(v.clone()).unwrap_err(), v);
+
+        let v = HexString::new([0x1a, 0x2b, 0x3c]);
+        assert_eq!(HexStringN::<2>::try_from(v.clone()).unwrap_err(), v);
     }
-}
 
-impl<const N: usize> AsRef<[u8; N]> for HexStringN<N> {
-    fn as_ref(&self) -> &[u8; N] {
-        &self.0
+    #[test]
+    fn into_vec() {
+        let v = HexStringN::<16>::filled(0x1a);
+        assert_eq!(v.to_hex_string().as_slice(), v.as_slice());
+        assert_eq!(HexString::from_hex_string_n(v.clone()), v);
+        assert_eq!(v.into_vec().len(), 16);
     }
-}
 
-impl<const N: usize> AsMut<[u8; N]> for HexStringN<N> {
-    fn as_mut(&mut self) -> &mut [u8; N] {
-        &mut self.0
+    #[test]
+    fn iter() {
+        let mut v = HexStringN::new([0x01, 0x02, 0x03]);
+        assert_eq!(v.iter().map(|&v| u32::from(v)).sum::<u32>(), 6);
+
+        for b in &mut v {
+            *b += 1;
+        }
+        assert_eq!(v, "020304");
+
+        let mut bytes = Vec::new();
+        for b in v {
+            bytes.push(b);
+        }
+        assert_eq!(bytes, [0x02, 0x03, 0x04]);
     }
-}
 
-impl<const N: usize> AsRef<[u8]> for HexStringN<N> {
-    fn as_ref(&self) -> &[u8] {
-        &*self.0
+    #[test]
+    fn default() {
+        assert_eq!(HexStringN::<32>::default(), HexStringN::zeroed());
+        assert_eq!(*Box::<HexStringN<32>>::default(), HexStringN::zeroed());
     }
-}
 
-impl<const N: usize> AsMut<[u8]> for HexStringN<N> {
-    fn as_mut(&mut self) -> &mut [u8] {
-        &mut *self.0
+    #[test]
+    fn index() {
+        let mut v = HexStringN::new([0x1a, 0x2b, 0x3c, 0x4d]);
+
+        assert_eq!(v[1], 0x2b);
+        assert_eq!(v[1..3], [0x2b, 0x3c]);
+        assert_eq!(v[..], [0x1a, 0x2b, 0x3c, 0x4d]);
+        assert_eq!(v[2..], [0x3c, 0x4d]);
+        assert_eq!(v[..2], [0x1a, 0x2b]);
+        assert_eq!(v[1..=2], [0x2b, 0x3c]);
+        assert_eq!(v[..=1], [0x1a, 0x2b]);
+
+        v[3] = 0xaa;
+        v[..2].copy_from_slice(&[0x00, 0x11]);
+        assert_eq!(v, "00113caa");
     }
-}
 
-impl<const N: usize> Borrow<[u8; N]> for HexStringN<N> {
-    fn borrow(&self) -> &[u8; N] {
-        self
+    #[test]
+    fn chunks_as() {
+        let v: HexStringN<16> = "000102030405060708090a0b0c0d0e0f".parse().unwrap();
+
+        let chunks = v.chunks_as::<4>();
+        assert_eq!(chunks.len(), 4);
+        for (i, chunk) in chunks.iter().enumerate() {
+            assert_eq!(chunk.as_slice(), &v[i * 4..(i + 1) * 4]);
+        }
+        assert_eq!(v.chunks_as::<16>(), [*v.as_array()]);
+        assert_eq!(v.chunks_as::<1>().len(), 16);
+        assert!(HexStringN::<0>::zeroed().chunks_as::<4>().is_empty());
     }
-}
 
-impl<const N: usize> BorrowMut<[u8; N]> for HexStringN<N> {
-    fn borrow_mut(&mut self) -> &mut [u8; N] {
-        self
+    #[test]
+    fn lower_upper_hex() {
+        let v = HexStringN::new([0x1a, 0x2b]);
+        assert_eq!(format!("{v:x}"), "1a2b");
+        assert_eq!(format!("{v:X}"), "1A2B");
+        assert_eq!(format!("{v:#x}"), "0x1a2b");
+        assert_eq!(format!("{v:#X}"), "0x1A2B");
+        assert_eq!(format!("{v:08x}"), "00001a2b");
+        assert_eq!(format!("{v:#08X}"), "0x001A2B");
+        assert_eq!(format!("{v:>6x}"), "  1a2b");
+        assert_eq!(format!("{v}"), format!("{v:x}"));
+        assert_ne!(format!("{v:?}"), format!("{v}"));
     }
-}
 
-impl<const N: usize> Borrow<[u8]> for HexStringN<N> {
-    fn borrow(&self) -> &[u8] {
-        self.as_slice()
+    #[test]
+    fn try_parse_collect_errors() {
+        assert_eq!(
+            HexStringN::<3>::try_parse_collect_errors("1a2B3c").unwrap(),
+            "1a2b3c"
+        );
+        assert_eq!(
+            HexStringN::<3>::try_parse_collect_errors("1a2b").unwrap_err(),
+            [HexStringNError::InvalidLength {
+                expected: 6,
+                encountered: 4
+            }]
+        );
+
+        let errors = HexStringN::<6>::try_parse_collect_errors("x01a2b3cy14d").unwrap_err();
+        let indices: Vec<_> = errors
+            .iter()
+            .map(|e| match e {
+                HexStringNError::InvalidByte { index, .. } => *index,
+                _ => unreachable!(),
+            })
+            .collect();
+        assert_eq!(indices, [0, 8]);
     }
-}
 
-impl<const N: usize> BorrowMut<[u8]> for HexStringN<N> {
-    fn borrow_mut(&mut self) -> &mut [u8] {
-        self.as_mut_slice()
+    #[test]
+    fn try_parse_separated() {
+        assert_eq!(HexStringN::<0>::try_parse_separated("", b':').unwrap(), "");
+        assert_eq!(
+            HexStringN::<6>::try_parse_separated("de:ad:BE:EF:01:23", b':').unwrap(),
+            "deadbeef0123"
+        );
+        assert_eq!(
+            HexStringN::<6>::try_parse_separated("de:ad:be:ef:01:23:45", b':'),
+            Err(HexStringNError::InvalidLength {
+                expected: 17,
+                encountered: 20
+            })
+        );
+        assert_eq!(
+            HexStringN::<0>::try_parse_separated("de", b':'),
+            Err(HexStringNError::InvalidLength {
+                expected: 0,
+                encountered: 2
+            })
+        );
+        assert_eq!(
+            HexStringN::<2>::try_parse_separated("de:a:", b':'),
+            Err(HexStringNError::UnexpectedSeparator { position: 4 })
+        );
+        assert_eq!(
+            HexStringN::<16>::try_parse_separated("67e55044-10b1-426f-9247-bb680e5fe0c8", b'-'),
+            Err(HexStringNError::MissingSeparator { position: 2 })
+        );
     }
-}
 
-impl<const N: usize> Borrow<HexStringN<N>> for Box<[u8; N]> {
-    fn borrow(&self) -> &HexStringN<N> {
-        // Safety: HexString is #[repr(transparent)]
-        unsafe { &*ptr::from_ref(self).cast() }
+    #[test]
+    fn format_separated() {
+        let v = HexStringN::new([0xde, 0xad, 0xbe, 0xef, 0x01, 0x23]);
+
+        assert_eq!(v.format_separated(':', 1), "de:ad:be:ef:01:23");
+        assert_eq!(v.format_separated(' ', 2), "dead beef 0123");
+        assert_eq!(v.format_separated(':', 6), v.to_lower());
+
+        for group_size in 1..=7 {
+            let mut s = String::new();
+            v.write_separated_to(&mut s, '-', group_size).unwrap();
+            assert_eq!(s, v.format_separated('-', group_size));
+        }
     }
-}
 
-impl<const N: usize> BorrowMut<HexStringN<N>> for Box<[u8; N]> {
-    fn borrow_mut(&mut self) -> &mut HexStringN<N> {
-        // Safety: HexString is #[repr(transparent)]
-        unsafe { &mut *ptr::from_mut(self).cast() }
+    #[test]
+    fn display_padding() {
+        let v = HexStringN::new([0x1a, 0x2b]);
+
+        assert_eq!(format!("{v:<8}"), "1a2b    ");
+        assert_eq!(format!("{v:>8}"), "    1a2b");
+        assert_eq!(format!("{v:^8}"), "  1a2b  ");
+        assert_eq!(format!("{v:*>8}"), "****1a2b");
+        assert_eq!(format!("{v:-^9}"), "--1a2b---");
+        assert_eq!(format!("{v:>2}"), "1a2b");
+        assert_eq!(format!("{v:.2}"), "1a");
+        assert_eq!(format!("{v}"), "1a2b");
     }
-}
 
-#[cfg(feature = "serde")]
-impl<'de, const N: usize> serde::Deserialize<'de> for HexStringN<N> {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
-    where
-        D: serde::Deserializer<'de>,
-    {
-        struct Visitor<const O: usize>;
+    #[cfg(feature = "getrandom")]
+    #[test]
+    fn try_random() {
+        assert_eq!(HexStringN::<0>::try_random().unwrap(), "");
+        assert_eq!(HexStringN::<1024>::try_random().unwrap().len(), 1024);
+
+        // probability of a collision is 2^-128
+        let a = HexStringN::<16>::try_random().unwrap();
+        let b = HexStringN::<16>::try_random().unwrap();
+        assert_ne!(a, b);
+    }
 
-        impl<'de, const O: usize> serde::de::Visitor<'de> for Visitor<O> {
-            type Value = HexStringN<O>;
+    #[test]
+    fn map_zip_with() {
+        let a = HexStringN::new([0x1a, 0x2b, 0x3c, 0xff]);
+        let b = HexStringN::new([0x00, 0xf0, 0x0f, 0xaa]);
+
+        assert_eq!(a.map(|v| v ^ 0xff), !&a);
+        assert_eq!(a.map(|v| v), a);
+        assert_eq!(a.zip_with(&b, |x, y| x ^ y), &a ^ &b);
+        assert_eq!(a.zip_with(&b, |x, y| x & y), &a & &b);
+        assert_eq!(a.zip_with(&b, |x, y| x | y), &a | &b);
+        assert_eq!(a.zip_with(&b, |x, _| x), a);
+
+        let mut v = a.clone();
+        v.map_inplace(|v| v.wrapping_add(1));
+        assert_eq!(v, "1b2c3d00");
+        v.zip_with_inplace(&a, u8::wrapping_sub);
+        assert_eq!(v, "01010101");
+
+        let mut calls = 0;
+        let _ = HexStringN::new([0; 8]).map(|v| {
+            calls += 1;
+            v
+        });
+        assert_eq!(calls, 8);
+    }
 
-            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_fmt(format_args!("hex string of length `{O}`"))
-            }
+    #[test]
+    fn try_parse_lax() {
+        assert_eq!(
+            HexStringN::<4>::try_parse_lax("1a 2b\n3c 4d").unwrap(),
+            "1a2b3c4d"
+        );
+        assert_eq!(HexStringN::<2>::try_parse_lax("  1a2b  ").unwrap(), "1a2b");
+        assert_eq!(
+            HexStringN::<4>::try_parse_lax("1a 2b 3c"),
+            Err(HexStringNError::InvalidLength {
+                expected: 8,
+                encountered: 6
+            })
+        );
+        assert_eq!(
+            HexStringN::<2>::try_parse_lax("1a\n\nx2"),
+            Err(HexStringNError::InvalidByte {
+                msb: b'x',
+                lsb: b'2',
+                index: 2
+            })
+        );
+    }
 
-            fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
-            where
-                E: serde::de::Error,
-            {
-                v.parse().map_err(|err| E::custom(err))
-            }
+    #[test]
+    fn arithmetic_bytes() {
+        let a = HexStringN::new([0xff, 0x00, 0x10, 0x80]);
+        let b = HexStringN::new([0x01, 0x01, 0x20, 0x80]);
+        let zero = HexStringN::new([0; 4]);
+
+        assert_eq!(a.wrapping_add_bytes(&b), "00013000");
+        assert_eq!(a.wrapping_sub_bytes(&b), "fefff000");
+        assert_eq!(a.saturating_add_bytes(&b), "ff0130ff");
+        assert_eq!(a.saturating_sub_bytes(&b), "fe000000");
+
+        assert_eq!(a.wrapping_add_bytes(&zero), a);
+        assert_eq!(a.wrapping_sub_bytes(&zero), a);
+        assert_eq!(a.saturating_add_bytes(&zero), a);
+        assert_eq!(a.saturating_sub_bytes(&zero), a);
+        assert_eq!(a.wrapping_sub_bytes(&a), zero);
+
+        let mut v = a.clone();
+        v.wrapping_add_bytes_inplace(&b);
+        assert_eq!(v, a.wrapping_add_bytes(&b));
+        v.wrapping_sub_bytes_inplace(&b);
+        assert_eq!(v, a);
+    }
+
+    #[test]
+    fn shift() {
+        let v = HexStringN::new([0x01, 0x02, 0x03]);
+
+        assert_eq!(v.shl_bytes(1), [0x02, 0x03, 0x00]);
+        assert_eq!(v.shr_bytes(1), [0x00, 0x01, 0x02]);
+        assert_eq!(v.shl_bytes(0), v);
+        assert_eq!(v.shr_bytes(0), v);
+        for n in [3, 4, usize::MAX] {
+            assert_eq!(v.shl_bytes(n), HexStringN::zeroed());
+            assert_eq!(v.shr_bytes(n), HexStringN::zeroed());
+            assert_eq!(v.shl_bits(n.saturating_mul(8)), HexStringN::zeroed());
         }
+        assert_eq!(HexStringN::<0>::zeroed().shl_bytes(1), "");
+        assert_eq!(HexStringN::<0>::zeroed().shr_bits(9), "");
 
-        deserializer.deserialize_str(Visitor)
+        let v = HexStringN::from_u128_be(0x0123_4567_89ab_cdef_fedc_ba98_7654_3210);
+        for n in 0..128 {
+            assert_eq!(v.shl_bits(n).to_u128_be(), v.to_u128_be() << n, "{n}");
+            assert_eq!(v.shr_bits(n).to_u128_be(), v.to_u128_be() >> n, "{n}");
+        }
+        assert_eq!(v.shl_bits(8), v.shl_bytes(1));
+        assert_eq!(v.shr_bits(16), v.shr_bytes(2));
     }
-}
 
-#[cfg(feature = "serde")]
-impl<const N: usize> serde::Serialize for HexStringN<N> {
-    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-    where
-        S: serde::Serializer,
-    {
-        self.to_string().serialize(serializer)
+    #[test]
+    fn leading_trailing_zeros() {
+        assert_eq!(HexStringN::<4>::zeroed().leading_zeros(), 32);
+        assert_eq!(HexStringN::<4>::zeroed().trailing_zeros(), 32);
+        assert_eq!(HexStringN::<4>::ones().leading_zeros(), 0);
+        assert_eq!(HexStringN::<4>::ones().trailing_zeros(), 0);
+        assert_eq!(HexStringN::<0>::zeroed().leading_zeros(), 0);
+        assert_eq!(HexStringN::<0>::zeroed().trailing_zeros(), 0);
+
+        for n in 0..128 {
+            let v = HexStringN::from_u128_be(1 << n);
+            assert_eq!(v.leading_zeros(), 127 - n);
+            assert_eq!(v.trailing_zeros(), n);
+            assert_eq!(v.count_ones(), 1);
+        }
+
+        let v = HexStringN::new([0x00, 0x00, 0x1a, 0x00, 0x2b, 0x00, 0x00]);
+        assert_eq!(v.leading_zeros(), 19);
+        assert_eq!(v.trailing_zeros(), 16);
+        assert_eq!(v.count_ones(), 7);
     }
-}
 
-#[cfg(feature = "rand")]
-impl<const N: usize> rand::distributions::Distribution<HexStringN<N>>
-    for rand::distributions::Standard
-{
-    fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> HexStringN<N> {
-        let mut ret: Box<[MaybeUninit<u8>; N]> = unsafe { Box::new_uninit().assume_init() };
-        for v in &mut *ret {
-            v.write(rng.gen());
+    #[test]
+    fn bits() {
+        let mut v = HexStringN::<2>::zeroed();
+
+        for n in [0, 7, 8, 15] {
+            assert!(!v.get_bit(n));
+            v.set_bit(n, true);
+            assert!(v.get_bit(n));
+            v.flip_bit(n);
+            assert!(!v.get_bit(n));
+            v.flip_bit(n);
+            assert!(v.get_bit(n));
         }
+        assert_eq!(v, "8181");
 
-        let ret: Box<[u8; N]> = unsafe { std::mem::transmute(ret) };
-        HexStringN::new(ret)
+        v.set_bit(7, false);
+        v.set_bit(8, false);
+        v.set_bit(15, true);
+        assert_eq!(v, "8001");
+        assert_eq!(v.count_ones(), 2);
+
+        let v = HexStringN::from_u16_be(0x1234);
+        for n in 0..16 {
+            assert_eq!(v.get_bit(n), v.to_u16_be() & (0x8000 >> n) != 0);
+        }
     }
-}
 
-#[cfg(all(test, feature = "rand"))]
-mod tests {
-    use rand::seq::SliceRandom;
+    #[test]
+    #[should_panic = "bit index out of bounds: the len is 16 but the index is 16"]
+    fn get_bit_out_of_bounds() {
+        let _ = HexStringN::<2>::zeroed().get_bit(16);
+    }
 
-    use super::HexStringN;
+    #[test]
+    #[should_panic = "bit index out of bounds"]
+    fn set_bit_out_of_bounds() {
+        HexStringN::<0>::zeroed().set_bit(0, true);
+    }
 
     #[test]
-    fn big_rand() {
-        // 128 * 1024 + 1
-        let _: HexStringN<131_073> = rand::random();
+    fn int_conversions_known_layouts() {
+        assert_eq!(
+            HexStringN::from_u128_be(u128::MAX),
+            HexStringN::<16>::ones()
+        );
+        assert_eq!(HexStringN::from_u128_le(0), HexStringN::<16>::zeroed());
+        assert_eq!(
+            HexStringN::from_u128_be(0x0102_0304_0506_0708_090a_0b0c_0d0e_0f10),
+            "0102030405060708090a0b0c0d0e0f10"
+        );
+        assert_eq!(
+            HexStringN::from_u128_le(0x0102_0304_0506_0708_090a_0b0c_0d0e_0f10),
+            "100f0e0d0c0b0a090807060504030201"
+        );
+        assert_eq!(
+            HexStringN::from_u64_be(0x1a2b_3c4d_5e6f_7a8b),
+            "1a2b3c4d5e6f7a8b"
+        );
+        assert_eq!(HexStringN::from_u32_be(0x1a2b_3c4d), "1a2b3c4d");
+        assert_eq!(HexStringN::from_u32_le(0x1a2b_3c4d), "4d3c2b1a");
+
+        for v in [0, 1, 0xdead_beef, u128::MAX / 3, u128::MAX] {
+            assert_eq!(HexStringN::from_u128_be(v).to_u128_be(), v);
+            assert_eq!(HexStringN::from_u128_le(v).to_u128_le(), v);
+            assert_eq!(HexStringN::from_u128_be(v).to_u128_le(), v.swap_bytes());
+        }
+        for v in [0, 1, 0xdead_beef, u64::MAX / 3, u64::MAX] {
+            assert_eq!(HexStringN::from_u64_be(v).to_u64_be(), v);
+            assert_eq!(HexStringN::from_u64_le(v).to_u64_le(), v);
+        }
+        for v in [0, 1, 0xdead_beef, u32::MAX] {
+            assert_eq!(HexStringN::from_u32_be(v).to_u32_be(), v);
+            assert_eq!(HexStringN::from_u32_le(v).to_u32_le(), v);
+        }
     }
 
     #[test]
-    fn big_hex() {
-        let mut rng = rand::thread_rng();
-        let v = (0..262_146).fold(String::new(), |mut acc, _| {
-            let v = *b"0123456789abcdefABCDEF".choose(&mut rng).unwrap();
-            acc.push(v.into());
-            acc
-        });
+    #[allow(clippy::float_cmp)]
+    fn byte_stats() {
+        assert_eq!(HexStringN::<32>::zeroed().average_byte(), 0.0);
+        assert_eq!(HexStringN::<32>::ones().average_byte(), 255.0);
+        assert_eq!(HexStringN::<32>::zeroed().min_byte(), 0);
+        assert_eq!(HexStringN::<32>::ones().max_byte(), 255);
+        assert_eq!(HexStringN::<0>::zeroed().average_byte(), 0.0);
+
+        let v = HexStringN::new([0x00, 0x01, 0x10, 0x80, 0xfe, 0x03]);
+        assert_eq!(v.min_byte(), 0x00);
+        assert_eq!(v.max_byte(), 0xfe);
+        assert_eq!(v.sum_bytes(), 0x192);
+        assert!((v.average_byte() - 67.0).abs() < f64::EPSILON);
+        assert!(v.average_byte() > f64::from(v.min_byte()));
+        assert!(v.average_byte() < f64::from(v.max_byte()));
+
+        assert_eq!(HexStringN::<1024>::ones().sum_bytes(), 1024 * 255);
+    }
 
-        // 128 * 1024 + 1
-        let parsed: HexStringN<131_073> = v.parse().unwrap();
-        assert_eq!(parsed.to_lower(), v.to_lowercase());
+    #[test]
+    fn encode_to_buf() {
+        let v = HexStringN::new([0x1a, 0x2b, 0xff]);
+
+        let mut buf = [0; 8];
+        let n = v.encode_upper_to_buf(&mut buf).unwrap();
+        assert_eq!(n, 6);
+        assert_eq!(core::str::from_utf8(&buf[..n]).unwrap(), v.to_upper());
+        assert_eq!(&buf[n..], [0, 0]);
+
+        assert_eq!(
+            v.encode_lower_to_buf(&mut buf[..5]),
+            Err(LengthError {
+                expected: 6,
+                encountered: 5
+            })
+        );
+    }
+
+    #[test]
+    fn write_to() {
+        use core::fmt::Write;
+
+        let v = HexStringN::new([0x00, 0x1a, 0xab, 0xff]);
+
+        let mut s = String::from("0x");
+        v.write_lower_to(&mut s).unwrap();
+        assert_eq!(s, format!("0x{}", v.to_lower()));
+
+        let mut s = String::new();
+        v.write_upper_to(&mut s).unwrap();
+        s.write_char('!').unwrap();
+        assert_eq!(s, format!("{}!", v.to_upper()));
+    }
+
+    #[test]
+    fn iter_nibbles() {
+        let v = HexStringN::new([0xab, 0xcd]);
+        assert_eq!(
+            v.iter_nibbles().collect::<Vec<_>>(),
+            [0x0a, 0x0b, 0x0c, 0x0d]
+        );
+        assert!(HexStringN::<32>::ones().iter_nibbles().all(|n| n == 15));
+        assert_eq!(HexStringN::<0>::zeroed().iter_nibbles().next(), None);
     }
 }
@@ -1,3 +1,4 @@
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
 #![warn(clippy::pedantic)]
 #![deny(rust_2018_idioms, unused, future_incompatible, nonstandard_style)]
 
@@ -9,23 +10,61 @@
 //! d41d8cd98f00b204e9800998ecf8427e
 //! ```
 //! ```
-//! use hex_str::{HexString, HexStringN};
+//! #[cfg(feature = "alloc")]
+//! {
+//!     use hex_str::{HexString, HexStringN};
 //!
-//! let s = "d41d8cd98f00b204e9800998ecf8427e";
+//!     let s = "d41d8cd98f00b204e9800998ecf8427e";
 //!
-//! // constant length, encoded in the type system
-//! let u = HexStringN::<16>::try_parse(s).unwrap();
-//! assert_eq!(u, "d41d8cd98f00b204e9800998ecf8427e");
+//!     // constant length, encoded in the type system
+//!     let u = HexStringN::<16>::try_parse(s).unwrap();
+//!     assert_eq!(u, "d41d8cd98f00b204e9800998ecf8427e");
 //!
-//! // variable length
-//! let v = HexString::try_parse(s).unwrap();
-//! assert_eq!(v, "d41d8cd98f00b204e9800998ecf8427e");
+//!     // variable length
+//!     let v = HexString::try_parse(s).unwrap();
+//!     assert_eq!(v, "d41d8cd98f00b204e9800998ecf8427e");
+//! }
 //! ```
 
 //! ## Feature flags:
-//! - `serde` - adds the ability to serialize, and deserialize [`HexString`]'s, and [`HexStringN`]'s using `serde`.
+//! - `std` (default) - adds the `io` based [`HexEncoder`] and [`HexDecoder`], implies `alloc`.
+//!   Without it the crate is `no_std`.
+//! - `alloc` - adds everything that allocates, [`HexString`], [`HexStringN`], [`ToHex`],
+//!   [`FromHex`], and [`HexParser`]. Without it only [`HexByte`], [`decode_hex_array`], the lazy
+//!   encoding iterators and the error types remain.
+//! - `serde` - adds the ability to serialize, and deserialize [`HexString`]'s, and [`HexStringN`]'s using `serde`,
+//!   as hex strings in human-readable formats and as raw bytes in binary ones.
+//!   Also adds `serde_hex` for `#[serde(with = "hex_str::serde_hex")]` on `[u8; N]` and `Vec<u8>` fields.
 //! - `rand` - adds implementation of `rand`'s [`Standard`](https://docs.rs/rand/0.8.4/rand/distributions/struct.Standard.html)
 //!   distribution, which enables random generation of [`HexStringN`]'s directly.
+//! - `getrandom` - adds `HexStringN::try_random` and `HexString::try_random`, which fill
+//!   values with cryptographically secure random bytes using `getrandom`, without depending on `rand`.
+//! - `proptest` - adds implementations of `proptest`'s [`Arbitrary`](https://docs.rs/proptest/1/proptest/arbitrary/trait.Arbitrary.html)
+//!   for [`HexString`]'s and [`HexStringN`]'s, for use in property-based tests.
+//! - `arbitrary` - adds implementations of `arbitrary`'s [`Arbitrary`](https://docs.rs/arbitrary/1/arbitrary/trait.Arbitrary.html)
+//!   for [`HexString`]'s and [`HexStringN`]'s, for structure-aware fuzzing.
+//! - `bytes` - adds conversions between [`HexString`] and `bytes`'s [`Bytes`](https://docs.rs/bytes/1/bytes/struct.Bytes.html),
+//!   and implements [`BufMut`](https://docs.rs/bytes/1/bytes/trait.BufMut.html) for [`HexString`].
+//! - `uuid` - adds conversions between `HexStringN<16>` and `uuid`'s [`Uuid`](https://docs.rs/uuid/1/uuid/struct.Uuid.html),
+//!   and `HexStringN::<16>::from_uuid_str` for parsing hyphenated UUIDs.
+//! - `sqlx` - adds implementations of `sqlx`'s `Type`, `Encode` and `Decode` for [`HexString`]'s
+//!   and [`HexStringN`]'s, stored as binary columns (`BYTEA`, `BLOB`), implies `std`.
+//! - `nom` - adds the `nom` module with parsers for use with `nom`'s combinators,
+//!   implies `alloc`.
+//! - `macros` - adds the `hex!` procedural macro, which expands hex literals to
+//!   [`HexStringN`]'s at compile time.
+//! - `zeroize` - adds implementations of `zeroize`'s [`Zeroize`](https://docs.rs/zeroize/1/zeroize/trait.Zeroize.html)
+//!   for [`HexString`]'s and [`HexStringN`]'s. Fields of these types in structs deriving
+//!   `ZeroizeOnDrop` are zeroed when the struct is dropped, [`Zeroizing`](https://docs.rs/zeroize/1/zeroize/struct.Zeroizing.html)
+//!   does the same for standalone values.
+//! - `subtle` - adds implementation of `subtle`'s [`ConstantTimeEq`](https://docs.rs/subtle/2/subtle/trait.ConstantTimeEq.html)
+//!   for [`HexStringN`]'s, for comparing secrets without leaking timing information.
+//! - `bytemuck` - adds implementations of `bytemuck`'s [`Pod`](https://docs.rs/bytemuck/1/bytemuck/trait.Pod.html)
+//!   and [`Zeroable`](https://docs.rs/bytemuck/1/bytemuck/trait.Zeroable.html) for [`HexByte`], allowing
+//!   byte buffers to be cast to `[HexByte]` without copying.
+//! - `zerocopy` - adds implementations of `zerocopy`'s [`FromBytes`](https://docs.rs/zerocopy/0.8/zerocopy/trait.FromBytes.html)
+//!   and [`IntoBytes`](https://docs.rs/zerocopy/0.8/zerocopy/trait.IntoBytes.html) for [`HexByte`], allowing
+//!   byte buffers to be reinterpreted as `[HexByte]` without copying.
 //!
 //! #### Using `serde` feature:
 //! ```
@@ -61,12 +100,75 @@
 //!     let _: HexStringN<16> = rand::random();
 //! }
 //! ```
+//!
+//! #### Using `zeroize` feature:
+//! ```
+//! #[cfg(feature = "zeroize")]
+//! {
+//!     use hex_str::HexStringN;
+//!     use zeroize::{Zeroize, ZeroizeOnDrop, Zeroizing};
+//!
+//!     #[derive(Zeroize, ZeroizeOnDrop)]
+//!     struct Secret {
+//!         key: HexStringN<32>,
+//!     }
+//!
+//!     let mut key = HexStringN::<4>::try_parse("deadbeef").unwrap();
+//!     key.zeroize();
+//!     assert_eq!(key, "00000000");
+//!
+//!     // zeroed once it goes out of scope
+//!     let _key = Zeroizing::new(HexStringN::<4>::try_parse("deadbeef").unwrap());
+//! }
+//! ```
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
 
 mod error;
+mod functions;
+mod hex_byte;
+#[cfg(feature = "alloc")]
 mod hex_string;
+#[cfg(feature = "alloc")]
 mod hex_string_n;
+mod macros;
+#[cfg(feature = "nom")]
+pub mod nom;
+#[cfg(feature = "alloc")]
+mod parser;
+#[cfg(all(feature = "serde", feature = "alloc"))]
+pub mod serde_hex;
+#[cfg(all(feature = "serde", feature = "alloc"))]
+pub mod serde_hex_lower;
+#[cfg(all(feature = "serde", feature = "alloc"))]
+pub mod serde_hex_upper;
+#[cfg(feature = "std")]
+mod stream;
+#[cfg(feature = "alloc")]
+mod traits;
+#[cfg_attr(not(feature = "alloc"), allow(dead_code))]
 mod utils;
 
-pub use error::{HexStringError, HexStringNError};
+pub use error::{HexStringError, HexStringNError, LengthError};
+#[cfg(feature = "alloc")]
+pub use functions::{decode_hex, encode_hex_lower, encode_hex_upper};
+pub use functions::{
+    decode_hex_array, encode_hex_lower_ascii_iter, encode_hex_lower_iter,
+    encode_hex_upper_ascii_iter, encode_hex_upper_iter, is_valid_hex_str, is_valid_lower_hex_str,
+    is_valid_upper_hex_str,
+};
+pub use hex_byte::HexByte;
+#[cfg(feature = "alloc")]
 pub use hex_string::HexString;
+#[cfg(feature = "alloc")]
 pub use hex_string_n::HexStringN;
+#[doc(hidden)]
+pub use macros::__private;
+#[cfg(feature = "alloc")]
+pub use parser::HexParser;
+
+#[cfg(feature = "std")]
+pub use stream::{HexDecoder, HexEncoder};
+#[cfg(feature = "alloc")]
+pub use traits::{FromHex, ToHex};
@@ -0,0 +1,140 @@
+/// Create a [`HexStringN`](crate::HexStringN) from a hex literal
+///
+/// The literal is parsed at compile time, both lowercase and uppercase
+/// characters allowed, and `N` is inferred from its length. Both string and
+/// byte string literals are accepted.
+///
+/// ## Example:
+/// ```
+/// use hex_str::{hex_string_n, HexStringN};
+///
+/// let v = hex_string_n!("1A2b");
+/// assert_eq!(v, HexStringN::new([0x1a, 0x2b]));
+///
+/// let v: HexStringN<4> = hex_string_n!(b"deadbeef");
+/// assert_eq!(v, "deadbeef");
+/// ```
+///
+/// Invalid literals fail to compile:
+/// ```compile_fail
+/// let _ = hex_str::hex_string_n!("gg");
+/// ```
+/// ```compile_fail
+/// let _ = hex_str::hex_string_n!("1a2");
+/// ```
+#[cfg(feature = "alloc")]
+#[macro_export]
+macro_rules! hex_string_n {
+    ($s:expr) => {
+        $crate::HexStringN::new(
+            const {
+                const BYTES: &[u8] = $crate::__private::Bytes($s).get();
+                match $crate::decode_hex_array::<{ BYTES.len() / 2 }>(BYTES) {
+                    Ok(v) => v,
+                    Err($crate::HexStringNError::InvalidLength { .. }) => {
+                        panic!("hex literal must have an even length")
+                    }
+                    Err(_) => panic!("hex literal contains characters other than `[0-9a-fA-F]`"),
+                }
+            },
+        )
+    };
+}
+
+/// Expand a hex literal to a [`HexStringN`](crate::HexStringN) at compile
+/// time
+///
+/// Both lowercase and uppercase characters are allowed, `N` is inferred from
+/// the length of the literal. String, byte string and raw string literals are
+/// accepted. Unlike [`hex_string_n!`], invalid literals are reported with an
+/// error pointing at the literal, naming the offending character.
+///
+/// ## Example:
+/// ```
+/// use hex_str::{hex, HexStringN};
+///
+/// let v = hex!("1A2b");
+/// assert_eq!(v, HexStringN::new([0x1a, 0x2b]));
+///
+/// let v: HexStringN<4> = hex!(b"deadbeef");
+/// assert_eq!(v, "deadbeef");
+///
+/// assert_eq!(hex!(r"1a2b"), hex!(br#"1a2b"#));
+/// ```
+///
+/// Invalid literals fail to compile:
+/// ```compile_fail
+/// let _ = hex_str::hex!("1g");
+/// ```
+/// ```compile_fail
+/// let _ = hex_str::hex!("1a2");
+/// ```
+#[cfg(feature = "macros")]
+#[macro_export]
+macro_rules! hex {
+    ($($tt:tt)*) => {
+        $crate::__private::hex!($crate, $($tt)*)
+    };
+}
+
+#[doc(hidden)]
+pub mod __private {
+    #[cfg(feature = "macros")]
+    pub use hex_str_macros::hex;
+
+    pub struct Bytes<T>(pub T);
+
+    impl<'a> Bytes<&'a str> {
+        #[must_use]
+        pub const fn get(self) -> &'a [u8] {
+            self.0.as_bytes()
+        }
+    }
+
+    impl<'a, const N: usize> Bytes<&'a [u8; N]> {
+        #[must_use]
+        pub const fn get(self) -> &'a [u8] {
+            self.0
+        }
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use crate::HexStringN;
+
+    #[test]
+    fn hex_string_n() {
+        assert_eq!(hex_string_n!("1a2b"), HexStringN::new([0x1a, 0x2b]));
+        assert_eq!(hex_string_n!("1A2b"), HexStringN::new([0x1a, 0x2b]));
+        assert_eq!(hex_string_n!(b"1a2B"), HexStringN::new([0x1a, 0x2b]));
+        assert_eq!(hex_string_n!(""), HexStringN::<0>::new([]));
+
+        let v: HexStringN<16> = hex_string_n!("d41d8cd98f00b204e9800998ecf8427e");
+        assert_eq!(
+            v,
+            HexStringN::<16>::try_parse("d41d8cd98f00b204e9800998ecf8427e").unwrap()
+        );
+    }
+
+    #[cfg(feature = "macros")]
+    #[test]
+    fn hex() {
+        assert_eq!(hex!("1a2b"), HexStringN::new([0x1a, 0x2b]));
+        assert_eq!(hex!("1A2b"), HexStringN::new([0x1a, 0x2b]));
+        assert_eq!(hex!(b"1a2B"), HexStringN::new([0x1a, 0x2b]));
+        assert_eq!(hex!(r"1a2b"), HexStringN::new([0x1a, 0x2b]));
+        assert_eq!(hex!(br#"1a2b"#), HexStringN::new([0x1a, 0x2b]));
+        assert_eq!(hex!(""), HexStringN::<0>::new([]));
+
+        macro_rules! forward {
+            ($s:literal) => {
+                hex!($s)
+            };
+        }
+        assert_eq!(
+            forward!("d41d8cd98f00b204e9800998ecf8427e"),
+            hex_string_n!("d41d8cd98f00b204e9800998ecf8427e")
+        );
+    }
+}
@@ -0,0 +1,134 @@
+//! Parsers for use with `nom`'s combinators
+//!
+//! ## Example:
+//! ```
+//! use hex_str::{nom::hex_array, HexStringN};
+//! use nom::{bytes::complete::tag, sequence::delimited};
+//!
+//! let mut parser = delimited(tag("prefix:"), hex_array::<4, ()>(), tag(":"));
+//!
+//! let (rest, v) = parser("prefix:1a2b3c4d:suffix").unwrap();
+//! assert_eq!(v, HexStringN::new([0x1a, 0x2b, 0x3c, 0x4d]));
+//! assert_eq!(rest, "suffix");
+//! ```
+
+use ::nom::{
+    bytes::complete::take,
+    error::{ErrorKind, ParseError},
+    Err, IResult,
+};
+
+use crate::{HexString, HexStringN};
+
+/// Return a parser consuming exactly `2 * N` hex characters, both lowercase
+/// and uppercase characters allowed.
+///
+/// The parser fails with [`ErrorKind::Eof`] if the input is too short, and
+/// with [`ErrorKind::HexDigit`] if it contains characters other than
+/// `[0-9a-fA-F]`.
+///
+/// # Example:
+/// ```
+/// use hex_str::nom::hex_array;
+///
+/// let (rest, v) = hex_array::<2, ()>()("1a2b3c").unwrap();
+/// assert_eq!(v, "1a2b");
+/// assert_eq!(rest, "3c");
+///
+/// assert!(hex_array::<2, ()>()("1a2").is_err());
+/// assert!(hex_array::<2, ()>()("1a2g").is_err());
+/// ```
+pub fn hex_array<'a, const N: usize, E: ParseError<&'a str>>(
+) -> impl FnMut(&'a str) -> IResult<&'a str, HexStringN<N>, E> {
+    move |input| {
+        let (rest, hex) = take(N * 2)(input)?;
+        let v = HexStringN::try_parse(hex)
+            .map_err(|_| Err::Error(E::from_error_kind(input, ErrorKind::HexDigit)))?;
+
+        Ok((rest, v))
+    }
+}
+
+/// Return a parser consuming exactly `2 * n` hex characters, both lowercase
+/// and uppercase characters allowed.
+///
+/// The parser fails with [`ErrorKind::Eof`] if the input is too short, and
+/// with [`ErrorKind::HexDigit`] if it contains characters other than
+/// `[0-9a-fA-F]`.
+///
+/// # Example:
+/// ```
+/// use hex_str::nom::hex_vector;
+///
+/// let (rest, v) = hex_vector::<()>(3)("1a2b3c4d").unwrap();
+/// assert_eq!(v, "1a2b3c");
+/// assert_eq!(rest, "4d");
+///
+/// assert!(hex_vector::<()>(3)("1a2b3").is_err());
+/// ```
+pub fn hex_vector<'a, E: ParseError<&'a str>>(
+    n: usize,
+) -> impl FnMut(&'a str) -> IResult<&'a str, HexString, E> {
+    move |input| {
+        let (rest, hex) = take(n * 2)(input)?;
+        let v = HexString::try_parse(hex)
+            .map_err(|_| Err::Error(E::from_error_kind(input, ErrorKind::HexDigit)))?;
+
+        Ok((rest, v))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ::nom::{
+        bytes::complete::tag,
+        character::complete::alpha1,
+        error::{Error, ErrorKind},
+        multi::separated_list1,
+        sequence::{terminated, tuple},
+        Err,
+    };
+
+    use super::{hex_array, hex_vector};
+    use crate::{HexString, HexStringN};
+
+    #[test]
+    fn combined() {
+        let mut parser = tuple((
+            terminated(alpha1::<_, Error<&str>>, tag(":")),
+            terminated(hex_array::<4, _>(), tag(":")),
+            alpha1,
+        ));
+
+        let (rest, (prefix, v, suffix)) = parser("prefix:1a2b3C4D:suffix!").unwrap();
+        assert_eq!(prefix, "prefix");
+        assert_eq!(v, HexStringN::new([0x1a, 0x2b, 0x3c, 0x4d]));
+        assert_eq!(suffix, "suffix");
+        assert_eq!(rest, "!");
+
+        let mut parser = separated_list1(tag("-"), hex_vector::<Error<&str>>(2));
+        let (rest, v) = parser("1a2b-3c4d-5e6f").unwrap();
+        assert_eq!(v, ["1a2b", "3c4d", "5e6f"]);
+        assert_eq!(rest, "");
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(
+            hex_array::<2, Error<&str>>()("1a2"),
+            Err(Err::Error(Error::new("1a2", ErrorKind::Eof)))
+        );
+        assert_eq!(
+            hex_array::<2, Error<&str>>()("1a2g3c"),
+            Err(Err::Error(Error::new("1a2g3c", ErrorKind::HexDigit)))
+        );
+        assert_eq!(
+            hex_vector::<Error<&str>>(1)("ég"),
+            Err(Err::Error(Error::new("ég", ErrorKind::HexDigit)))
+        );
+        assert_eq!(
+            hex_vector::<Error<&str>>(0)("1a"),
+            Ok(("1a", HexString::new([])))
+        );
+    }
+}
@@ -0,0 +1,192 @@
+use alloc::vec::Vec;
+
+use crate::{utils, HexString, HexStringError};
+
+/// An incremental hex parser, fed input a chunk at a time
+///
+/// Chunks may be split at any point, including in the middle of a pair.
+/// Reported indices are relative to the start of the whole input.
+///
+/// ## Example:
+/// ```
+/// use hex_str::HexParser;
+///
+/// let mut parser = HexParser::new();
+/// parser.feed("1a").unwrap();
+/// parser.feed("2").unwrap();
+/// parser.feed("B3c").unwrap();
+///
+/// assert_eq!(parser.finish().unwrap(), "1a2b3c");
+/// ```
+#[derive(Debug, Clone)]
+pub struct HexParser {
+    bytes: Vec<u8>,
+    conversion_fn: fn(u8, u8) -> Option<u8>,
+    pending: Option<u8>,
+    position: usize,
+}
+
+impl HexParser {
+    /// Create a new `HexParser`, both lowercase and uppercase characters
+    /// allowed.
+    ///
+    /// # Example:
+    /// ```
+    /// use hex_str::HexParser;
+    ///
+    /// let mut parser = HexParser::new();
+    /// parser.feed("aB").unwrap();
+    /// assert_eq!(parser.finish().unwrap(), "ab");
+    /// ```
+    #[must_use]
+    pub fn new() -> Self {
+        Self::with_conversion_fn(utils::parse)
+    }
+
+    /// Create a new `HexParser`, only lowercase characters allowed.
+    ///
+    /// # Example:
+    /// ```
+    /// use hex_str::HexParser;
+    ///
+    /// let mut parser = HexParser::new_lower();
+    /// assert!(parser.feed("AB").is_err());
+    /// ```
+    #[must_use]
+    pub fn new_lower() -> Self {
+        Self::with_conversion_fn(utils::parse_lower)
+    }
+
+    /// Create a new `HexParser`, only uppercase characters allowed.
+    ///
+    /// # Example:
+    /// ```
+    /// use hex_str::HexParser;
+    ///
+    /// let mut parser = HexParser::new_upper();
+    /// assert!(parser.feed("ab").is_err());
+    /// ```
+    #[must_use]
+    pub fn new_upper() -> Self {
+        Self::with_conversion_fn(utils::parse_upper)
+    }
+
+    fn with_conversion_fn(conversion_fn: fn(u8, u8) -> Option<u8>) -> Self {
+        Self {
+            bytes: Vec::new(),
+            conversion_fn,
+            pending: None,
+            position: 0,
+        }
+    }
+
+    /// Parse `chunk` and append the result to the bytes parsed so far.
+    ///
+    /// # Errors
+    /// - if `chunk` contains invalid characters, `self` is left unchanged
+    ///
+    /// # Example:
+    /// ```
+    /// use hex_str::{HexParser, HexStringError};
+    ///
+    /// let mut parser = HexParser::new();
+    /// parser.feed("1a2").unwrap();
+    /// assert_eq!(
+    ///     parser.feed("g"),
+    ///     Err(HexStringError::InvalidByte { msb: b'2', lsb: b'g', index: 2 })
+    /// );
+    /// ```
+    pub fn feed(&mut self, chunk: impl AsRef<[u8]>) -> Result<(), HexStringError> {
+        let len = self.bytes.len();
+        let mut pending = self.pending;
+
+        for (i, &c) in chunk.as_ref().iter().enumerate() {
+            let Some(a) = pending.take() else {
+                pending = Some(c);
+                continue;
+            };
+
+            let Some(v) = (self.conversion_fn)(a, c) else {
+                self.bytes.truncate(len);
+                return Err(HexStringError::InvalidByte {
+                    msb: a,
+                    lsb: c,
+                    index: self.position + i - 1,
+                });
+            };
+
+            self.bytes.push(v);
+        }
+
+        self.pending = pending;
+        self.position += chunk.as_ref().len();
+
+        Ok(())
+    }
+
+    /// Finish parsing and return the parsed bytes.
+    ///
+    /// # Errors
+    /// - if an odd number of characters was fed
+    ///
+    /// # Example:
+    /// ```
+    /// use hex_str::{HexParser, HexStringError};
+    ///
+    /// let mut parser = HexParser::new();
+    /// parser.feed("1a2").unwrap();
+    /// assert_eq!(
+    ///     parser.finish(),
+    ///     Err(HexStringError::InvalidLength { encountered: 3 })
+    /// );
+    /// ```
+    pub fn finish(self) -> Result<HexString, HexStringError> {
+        if self.pending.is_some() {
+            return Err(HexStringError::InvalidLength {
+                encountered: self.position,
+            });
+        }
+
+        Ok(HexString::new(self.bytes))
+    }
+}
+
+impl Default for HexParser {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::HexParser;
+    use crate::HexStringError;
+
+    #[test]
+    fn parser() {
+        let mut parser = HexParser::new();
+        parser.feed("1a").unwrap();
+        parser.feed("2b").unwrap();
+        parser.feed("").unwrap();
+        parser.feed("3c").unwrap();
+        assert_eq!(parser.clone().finish().unwrap(), [0x1a, 0x2b, 0x3c]);
+
+        parser.feed("4").unwrap();
+        parser.feed("D5").unwrap();
+        assert_eq!(
+            parser.feed("e6fzz"),
+            Err(HexStringError::InvalidByte {
+                msb: b'z',
+                lsb: b'z',
+                index: 12
+            })
+        );
+        assert_eq!(
+            parser.clone().finish(),
+            Err(HexStringError::InvalidLength { encountered: 9 })
+        );
+
+        parser.feed("e").unwrap();
+        assert_eq!(parser.finish().unwrap(), "1a2b3c4d5e");
+    }
+}
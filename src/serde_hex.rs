@@ -0,0 +1,151 @@
+//! Serialize and deserialize byte fields as hex strings, lowercase
+//!
+//! For use with `#[serde(with = "hex_str::serde_hex")]` on fields of any type
+//! implementing both `AsRef<[u8]>` and [`FromHex`], such as `[u8; N]` and
+//! `Vec<u8>`. Deserialization allows both lowercase and uppercase
+//! characters, see [`serde_hex_lower`](crate::serde_hex_lower) and
+//! [`serde_hex_upper`](crate::serde_hex_upper) for the strict variants.
+//!
+//! ## Example:
+//! ```
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Deserialize, Serialize)]
+//! struct Example {
+//!     #[serde(with = "hex_str::serde_hex")]
+//!     md5: [u8; 16],
+//!     #[serde(with = "hex_str::serde_hex")]
+//!     data: Vec<u8>,
+//! }
+//!
+//! let s = r#"{"md5":"d41d8cd98f00b204e9800998ecf8427e","data":"1A2B"}"#;
+//!
+//! let example: Example = serde_json::from_str(s).unwrap();
+//! assert_eq!(example.md5[0], 0xd4);
+//! assert_eq!(example.data, [0x1a, 0x2b]);
+//!
+//! let s = serde_json::to_string(&example).unwrap();
+//! assert_eq!(s, r#"{"md5":"d41d8cd98f00b204e9800998ecf8427e","data":"1a2b"}"#);
+//! ```
+
+use core::fmt::Display;
+
+use alloc::string::String;
+
+use serde::{Deserialize, Deserializer, Serializer};
+
+use crate::{FromHex, ToHex};
+
+/// Serialize `v` as a hex string, lowercase.
+///
+/// # Errors
+/// - if `serializer` fails
+pub fn serialize<T, S>(v: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: AsRef<[u8]> + ?Sized,
+    S: Serializer,
+{
+    serializer.serialize_str(&v.to_hex_lower())
+}
+
+/// Deserialize a hex string, both lowercase and uppercase characters allowed.
+///
+/// # Errors
+/// - if `deserializer` fails, or doesn't contain a string
+/// - if the string isn't valid hex for `T`
+pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+where
+    T: FromHex,
+    T::Error: Display,
+    D: Deserializer<'de>,
+{
+    deserialize_with(deserializer, T::from_hex)
+}
+
+pub(crate) fn deserialize_with<'de, T, D>(
+    deserializer: D,
+    parse_fn: impl FnOnce(String) -> Result<T, T::Error>,
+) -> Result<T, D::Error>
+where
+    T: FromHex,
+    T::Error: Display,
+    D: Deserializer<'de>,
+{
+    let s = String::deserialize(deserializer)?;
+    parse_fn(s).map_err(serde::de::Error::custom)
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::{Deserialize, Serialize};
+
+    use crate::{HexString, HexStringN};
+
+    #[derive(Debug, PartialEq, Deserialize, Serialize)]
+    struct Example {
+        #[serde(with = "crate::serde_hex")]
+        array: [u8; 16],
+        #[serde(with = "crate::serde_hex")]
+        vec: Vec<u8>,
+        #[serde(with = "crate::serde_hex_lower")]
+        lower: HexStringN<2>,
+        #[serde(with = "crate::serde_hex_upper")]
+        upper: HexString,
+    }
+
+    #[test]
+    fn round_trip() {
+        let example = Example {
+            array: [0xab; 16],
+            vec: vec![0x1a, 0x2b],
+            lower: HexStringN::new([0x3c, 0x4d]),
+            upper: HexString::new([0x5e, 0x6f]),
+        };
+
+        let s = serde_json::to_string(&example).unwrap();
+        assert_eq!(
+            s,
+            format!(
+                r#"{{"array":"{}","vec":"1a2b","lower":"3c4d","upper":"5E6F"}}"#,
+                "ab".repeat(16)
+            )
+        );
+        assert_eq!(serde_json::from_str::<Example>(&s).unwrap(), example);
+    }
+
+    #[test]
+    fn errors() {
+        let s = format!(
+            r#"{{"array":"{}","vec":"1A2B","lower":"3c4d","upper":"5E6F"}}"#,
+            "AB".repeat(16)
+        );
+        assert!(serde_json::from_str::<Example>(&s).is_ok());
+
+        let s = format!(
+            r#"{{"array":"{}","vec":"1a2b","lower":"3c4d","upper":"5E6F"}}"#,
+            "ab".repeat(15)
+        );
+        let err = serde_json::from_str::<Example>(&s).unwrap_err();
+        assert!(err
+            .to_string()
+            .starts_with("invalid input length, expected `32`, encountered: `30`"));
+
+        let s = format!(
+            r#"{{"array":"{}","vec":"1a2b","lower":"3C4D","upper":"5E6F"}}"#,
+            "ab".repeat(16)
+        );
+        assert!(serde_json::from_str::<Example>(&s).is_err());
+
+        let s = format!(
+            r#"{{"array":"{}","vec":"1a2b","lower":"3c4d","upper":"5e6f"}}"#,
+            "ab".repeat(16)
+        );
+        assert!(serde_json::from_str::<Example>(&s).is_err());
+
+        let s = format!(
+            r#"{{"array":"{}","vec":[26, 43],"lower":"3c4d","upper":"5E6F"}}"#,
+            "ab".repeat(16)
+        );
+        assert!(serde_json::from_str::<Example>(&s).is_err());
+    }
+}
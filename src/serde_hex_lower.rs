@@ -0,0 +1,53 @@
+//! Serialize and deserialize byte fields as hex strings, lowercase only
+//!
+//! Same as [`serde_hex`], except that deserialization
+//! rejects uppercase characters.
+//!
+//! ## Example:
+//! ```
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Deserialize, Serialize)]
+//! struct Example {
+//!     #[serde(with = "hex_str::serde_hex_lower")]
+//!     data: Vec<u8>,
+//! }
+//!
+//! let example: Example = serde_json::from_str(r#"{"data":"1a2b"}"#).unwrap();
+//! assert_eq!(example.data, [0x1a, 0x2b]);
+//! assert_eq!(serde_json::to_string(&example).unwrap(), r#"{"data":"1a2b"}"#);
+//!
+//! assert!(serde_json::from_str::<Example>(r#"{"data":"1A2B"}"#).is_err());
+//! ```
+
+use core::fmt::Display;
+
+use serde::{Deserializer, Serializer};
+
+use crate::{serde_hex, FromHex, ToHex};
+
+/// Serialize `v` as a hex string, lowercase.
+///
+/// # Errors
+/// - if `serializer` fails
+pub fn serialize<T, S>(v: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: AsRef<[u8]> + ?Sized,
+    S: Serializer,
+{
+    serializer.serialize_str(&v.to_hex_lower())
+}
+
+/// Deserialize a hex string, only lowercase characters allowed.
+///
+/// # Errors
+/// - if `deserializer` fails, or doesn't contain a string
+/// - if the string isn't valid lowercase hex for `T`
+pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+where
+    T: FromHex,
+    T::Error: Display,
+    D: Deserializer<'de>,
+{
+    serde_hex::deserialize_with(deserializer, T::from_hex_lower)
+}
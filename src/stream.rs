@@ -0,0 +1,365 @@
+use std::io::{self, Write};
+
+use crate::{utils, HexStringError};
+
+/// A writer that hex encodes everything written to it and forwards the
+/// result to an inner writer
+///
+/// ## Example:
+/// ```
+/// use std::io::Write;
+///
+/// use hex_str::HexEncoder;
+///
+/// let mut encoder = HexEncoder::new_lower(Vec::new());
+/// encoder.write_all(&[0x1a, 0x2b]).unwrap();
+/// encoder.write_all(&[0x3c]).unwrap();
+///
+/// assert_eq!(encoder.finish().unwrap(), b"1a2b3c");
+/// ```
+#[derive(Debug)]
+pub struct HexEncoder<W: Write> {
+    inner: W,
+    conversion_fn: fn(u8) -> [u8; 2],
+}
+
+impl<W: Write> HexEncoder<W> {
+    /// Create a new `HexEncoder`, writing lowercase characters to `inner`.
+    ///
+    /// # Example:
+    /// ```
+    /// use std::io::Write;
+    ///
+    /// use hex_str::HexEncoder;
+    ///
+    /// let mut encoder = HexEncoder::new_lower(Vec::new());
+    /// encoder.write_all(&[0xab]).unwrap();
+    /// assert_eq!(encoder.finish().unwrap(), b"ab");
+    /// ```
+    pub fn new_lower(inner: W) -> Self {
+        Self {
+            inner,
+            conversion_fn: utils::to_hex_lower,
+        }
+    }
+
+    /// Create a new `HexEncoder`, writing uppercase characters to `inner`.
+    ///
+    /// # Example:
+    /// ```
+    /// use std::io::Write;
+    ///
+    /// use hex_str::HexEncoder;
+    ///
+    /// let mut encoder = HexEncoder::new_upper(Vec::new());
+    /// encoder.write_all(&[0xab]).unwrap();
+    /// assert_eq!(encoder.finish().unwrap(), b"AB");
+    /// ```
+    pub fn new_upper(inner: W) -> Self {
+        Self {
+            inner,
+            conversion_fn: utils::to_hex_upper,
+        }
+    }
+
+    /// Flush `self` and return the inner writer.
+    ///
+    /// # Errors
+    /// - if flushing the inner writer fails
+    ///
+    /// # Example:
+    /// ```
+    /// use std::io::{Cursor, Write};
+    ///
+    /// use hex_str::HexEncoder;
+    ///
+    /// let mut encoder = HexEncoder::new_lower(Cursor::new(Vec::new()));
+    /// encoder.write_all(&[0x1a]).unwrap();
+    /// assert_eq!(encoder.finish().unwrap().into_inner(), b"1a");
+    /// ```
+    pub fn finish(mut self) -> io::Result<W> {
+        self.inner.flush()?;
+        Ok(self.inner)
+    }
+}
+
+impl<W: Write> Write for HexEncoder<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let mut encoded = [0; 512];
+        let len = buf.len().min(encoded.len() / 2);
+
+        for (v, dst) in buf[..len].iter().zip(encoded.chunks_exact_mut(2)) {
+            dst.copy_from_slice(&(self.conversion_fn)(*v));
+        }
+
+        // a byte is either fully written or not at all, otherwise a
+        // retry would produce malformed output
+        self.inner.write_all(&encoded[..len * 2])?;
+        Ok(len)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+/// A writer that hex decodes everything written to it and forwards the
+/// result to an inner writer
+///
+/// Input may be split at any point, at most a single character is buffered
+/// between writes. Invalid input is reported as an [`io::Error`] of kind
+/// [`io::ErrorKind::InvalidData`], wrapping a [`HexStringError`] with the
+/// index relative to the start of the stream.
+///
+/// The [`HexStringError`] isn't returned by the [`io::Error`]'s
+/// [`source`](std::error::Error::source), which forwards to the wrapped
+/// error's own `source`. It's reachable through [`io::Error::get_ref`] or
+/// [`io::Error::into_inner`] and a `downcast`.
+///
+/// ## Example:
+/// ```
+/// use std::io::Write;
+///
+/// use hex_str::{HexDecoder, HexStringError};
+///
+/// let mut decoder = HexDecoder::new(Vec::new());
+/// decoder.write_all(b"1a2").unwrap();
+/// decoder.write_all(b"B3c").unwrap();
+///
+/// assert_eq!(decoder.finish().unwrap(), [0x1a, 0x2b, 0x3c]);
+///
+/// let mut decoder = HexDecoder::new(Vec::new());
+/// let err = decoder.write_all(b"1g").unwrap_err();
+/// let err = err.get_ref().unwrap().downcast_ref::<HexStringError>();
+/// assert_eq!(
+///     err,
+///     Some(&HexStringError::InvalidByte { msb: b'1', lsb: b'g', index: 0 })
+/// );
+/// ```
+#[derive(Debug)]
+pub struct HexDecoder<W: Write> {
+    inner: W,
+    conversion_fn: fn(u8, u8) -> Option<u8>,
+    pending: Option<u8>,
+    position: usize,
+}
+
+impl<W: Write> HexDecoder<W> {
+    /// Create a new `HexDecoder`, both lowercase and uppercase characters
+    /// allowed.
+    ///
+    /// # Example:
+    /// ```
+    /// use std::io::Write;
+    ///
+    /// use hex_str::HexDecoder;
+    ///
+    /// let mut decoder = HexDecoder::new(Vec::new());
+    /// decoder.write_all(b"aB").unwrap();
+    /// assert_eq!(decoder.finish().unwrap(), [0xab]);
+    /// ```
+    pub fn new(inner: W) -> Self {
+        Self::with_conversion_fn(inner, utils::parse)
+    }
+
+    /// Create a new `HexDecoder`, only lowercase characters allowed.
+    ///
+    /// # Example:
+    /// ```
+    /// use std::io::Write;
+    ///
+    /// use hex_str::HexDecoder;
+    ///
+    /// let mut decoder = HexDecoder::new_lower(Vec::new());
+    /// assert!(decoder.write_all(b"AB").is_err());
+    /// ```
+    pub fn new_lower(inner: W) -> Self {
+        Self::with_conversion_fn(inner, utils::parse_lower)
+    }
+
+    /// Create a new `HexDecoder`, only uppercase characters allowed.
+    ///
+    /// # Example:
+    /// ```
+    /// use std::io::Write;
+    ///
+    /// use hex_str::HexDecoder;
+    ///
+    /// let mut decoder = HexDecoder::new_upper(Vec::new());
+    /// assert!(decoder.write_all(b"ab").is_err());
+    /// ```
+    pub fn new_upper(inner: W) -> Self {
+        Self::with_conversion_fn(inner, utils::parse_upper)
+    }
+
+    fn with_conversion_fn(inner: W, conversion_fn: fn(u8, u8) -> Option<u8>) -> Self {
+        Self {
+            inner,
+            conversion_fn,
+            pending: None,
+            position: 0,
+        }
+    }
+
+    /// Flush `self` and return the inner writer.
+    ///
+    /// # Errors
+    /// - if an odd number of characters was written, the error wraps
+    ///   [`HexStringError::InvalidLength`]
+    /// - if flushing the inner writer fails
+    ///
+    /// # Example:
+    /// ```
+    /// use std::io::{ErrorKind, Write};
+    ///
+    /// use hex_str::HexDecoder;
+    ///
+    /// let mut decoder = HexDecoder::new(Vec::new());
+    /// decoder.write_all(b"1a2").unwrap();
+    /// assert_eq!(decoder.finish().unwrap_err().kind(), ErrorKind::InvalidData);
+    /// ```
+    pub fn finish(mut self) -> io::Result<W> {
+        if self.pending.is_some() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                HexStringError::InvalidLength {
+                    encountered: self.position,
+                },
+            ));
+        }
+
+        self.inner.flush()?;
+        Ok(self.inner)
+    }
+}
+
+impl<W: Write> Write for HexDecoder<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let mut decoded = [0; 256];
+        let mut len = 0;
+        let mut pending = self.pending;
+        let mut consumed = 0;
+
+        for &c in buf {
+            match pending {
+                None => pending = Some(c),
+                Some(_) if len == decoded.len() => break,
+                Some(a) => {
+                    let Some(v) = (self.conversion_fn)(a, c) else {
+                        if consumed == 0 {
+                            return Err(io::Error::new(
+                                io::ErrorKind::InvalidData,
+                                HexStringError::InvalidByte {
+                                    msb: a,
+                                    lsb: c,
+                                    index: self.position - 1,
+                                },
+                            ));
+                        }
+
+                        // report the error on the next call
+                        break;
+                    };
+
+                    decoded[len] = v;
+                    len += 1;
+                    pending = None;
+                }
+            }
+
+            consumed += 1;
+        }
+
+        self.inner.write_all(&decoded[..len])?;
+        self.pending = pending;
+        self.position += consumed;
+
+        Ok(consumed)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{Cursor, ErrorKind, Write};
+
+    use super::{HexDecoder, HexEncoder};
+    use crate::{encode_hex_lower, encode_hex_upper, HexStringError};
+
+    #[test]
+    fn encoder() {
+        let bytes: Vec<u8> = (0..=u8::MAX).cycle().take(2000).collect();
+
+        let mut encoder = HexEncoder::new_lower(Cursor::new(Vec::new()));
+        for chunk in bytes.chunks(7) {
+            encoder.write_all(chunk).unwrap();
+        }
+        let out = encoder.finish().unwrap().into_inner();
+        assert_eq!(out, encode_hex_lower(&bytes).as_bytes());
+
+        let mut encoder = HexEncoder::new_upper(Cursor::new(Vec::new()));
+        encoder.write_all(&bytes).unwrap();
+        encoder.write_all(&[]).unwrap();
+        let out = encoder.finish().unwrap().into_inner();
+        assert_eq!(out, encode_hex_upper(&bytes).as_bytes());
+    }
+
+    #[test]
+    fn decoder() {
+        let bytes: Vec<u8> = (0..=u8::MAX).cycle().take(2000).collect();
+        let hex = encode_hex_upper(&bytes);
+
+        let mut decoder = HexDecoder::new(Cursor::new(Vec::new()));
+        for c in hex.as_bytes().chunks(1) {
+            decoder.write_all(c).unwrap();
+        }
+        assert_eq!(decoder.finish().unwrap().into_inner(), bytes);
+
+        let mut decoder = HexDecoder::new(Vec::new());
+        decoder.write_all(hex.as_bytes()).unwrap();
+        assert_eq!(decoder.finish().unwrap(), bytes);
+    }
+
+    #[test]
+    fn decoder_errors() {
+        let inner_error = |e: std::io::Error| {
+            assert_eq!(e.kind(), ErrorKind::InvalidData);
+            *e.into_inner()
+                .unwrap()
+                .downcast::<HexStringError>()
+                .unwrap()
+        };
+
+        let mut decoder = HexDecoder::new(Vec::new());
+        decoder.write_all(b"1a2").unwrap();
+        let err = decoder.write_all(b"b3g4d").unwrap_err();
+        assert_eq!(
+            inner_error(err),
+            HexStringError::InvalidByte {
+                msb: b'3',
+                lsb: b'g',
+                index: 4
+            }
+        );
+
+        let mut decoder = HexDecoder::new_lower(Vec::new());
+        decoder.write_all(b"1a2b3").unwrap();
+        assert_eq!(
+            inner_error(decoder.finish().unwrap_err()),
+            HexStringError::InvalidLength { encountered: 5 }
+        );
+    }
+
+    #[test]
+    fn encoder_decoder_round_trip() {
+        let bytes: Vec<u8> = (0..=u8::MAX).rev().cycle().take(1000).collect();
+
+        let mut encoder = HexEncoder::new_lower(HexDecoder::new_lower(Vec::new()));
+        encoder.write_all(&bytes).unwrap();
+        let decoder = encoder.finish().unwrap();
+        assert_eq!(decoder.finish().unwrap(), bytes);
+    }
+}
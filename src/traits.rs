@@ -0,0 +1,305 @@
+use alloc::{boxed::Box, string::String, vec::Vec};
+
+use crate::{utils, HexString, HexStringError, HexStringN, HexStringNError};
+
+/// Encode byte sequences as hex strings
+///
+/// Implemented for everything that implements `AsRef<[u8]>`.
+///
+/// ## Example:
+/// ```
+/// use hex_str::{HexStringN, ToHex};
+///
+/// assert_eq!([0x1a, 0x2b].to_hex_lower(), "1a2b");
+/// assert_eq!(vec![0x1a, 0x2b].to_hex_upper(), "1A2B");
+/// assert_eq!(HexStringN::new([0x1a, 0x2b]).to_hex_lower(), "1a2b");
+/// ```
+pub trait ToHex {
+    /// Return the hex representation of `self`, lowercase.
+    ///
+    /// # Example:
+    /// ```
+    /// use hex_str::ToHex;
+    ///
+    /// assert_eq!(b"\x1a\x2b".to_hex_lower(), "1a2b");
+    /// ```
+    fn to_hex_lower(&self) -> String;
+
+    /// Return the hex representation of `self`, uppercase.
+    ///
+    /// # Example:
+    /// ```
+    /// use hex_str::ToHex;
+    ///
+    /// assert_eq!(b"\x1a\x2b".to_hex_upper(), "1A2B");
+    /// ```
+    fn to_hex_upper(&self) -> String;
+
+    /// Write the hex representation of `self` into `w`, lowercase.
+    ///
+    /// # Errors
+    /// - if `w` fails to write
+    ///
+    /// # Example:
+    /// ```
+    /// use hex_str::ToHex;
+    ///
+    /// let mut s = String::from("0x");
+    /// [0x1a, 0x2b].write_hex_lower_to(&mut s).unwrap();
+    /// assert_eq!(s, "0x1a2b");
+    /// ```
+    fn write_hex_lower_to(&self, w: &mut impl core::fmt::Write) -> core::fmt::Result;
+
+    /// Write the hex representation of `self` into `w`, uppercase.
+    ///
+    /// # Errors
+    /// - if `w` fails to write
+    ///
+    /// # Example:
+    /// ```
+    /// use hex_str::ToHex;
+    ///
+    /// let mut s = String::from("0x");
+    /// [0x1a, 0x2b].write_hex_upper_to(&mut s).unwrap();
+    /// assert_eq!(s, "0x1A2B");
+    /// ```
+    fn write_hex_upper_to(&self, w: &mut impl core::fmt::Write) -> core::fmt::Result;
+}
+
+impl<T: AsRef<[u8]> + ?Sized> ToHex for T {
+    fn to_hex_lower(&self) -> String {
+        self.as_ref()
+            .iter()
+            .copied()
+            .flat_map(utils::to_hex_lower)
+            .map(char::from)
+            .collect()
+    }
+
+    fn to_hex_upper(&self) -> String {
+        self.as_ref()
+            .iter()
+            .copied()
+            .flat_map(utils::to_hex_upper)
+            .map(char::from)
+            .collect()
+    }
+
+    fn write_hex_lower_to(&self, w: &mut impl core::fmt::Write) -> core::fmt::Result {
+        utils::write_to(self.as_ref(), w, utils::to_hex_lower)
+    }
+
+    fn write_hex_upper_to(&self, w: &mut impl core::fmt::Write) -> core::fmt::Result {
+        utils::write_to(self.as_ref(), w, utils::to_hex_upper)
+    }
+}
+
+/// Decode hex strings into various target types
+///
+/// Lets generic code parse hex without knowing the concrete type.
+///
+/// ## Example:
+/// ```
+/// use hex_str::{FromHex, HexString, HexStringN};
+///
+/// fn parse_key<K: FromHex>(s: &str) -> Result<K, K::Error> {
+///     K::from_hex(s)
+/// }
+///
+/// assert_eq!(parse_key::<Vec<u8>>("1a2b"), Ok(vec![0x1a, 0x2b]));
+/// assert_eq!(parse_key::<[u8; 2]>("1a2b"), Ok([0x1a, 0x2b]));
+/// assert_eq!(parse_key::<HexStringN<2>>("1a2b").unwrap(), "1a2b");
+/// assert_eq!(parse_key::<HexString>("1a2b").unwrap(), "1a2b");
+/// ```
+pub trait FromHex: Sized {
+    /// The error returned when `bytes` isn't valid hex for the target type
+    type Error;
+
+    /// Try to parse `bytes`, both lowercase and uppercase characters allowed.
+    ///
+    /// # Errors
+    /// - if `bytes` doesn't have a valid length for the target type
+    /// - if `bytes` contains characters other than `[0-9a-fA-F]`
+    ///
+    /// # Example:
+    /// ```
+    /// use hex_str::FromHex;
+    ///
+    /// assert_eq!(Vec::<u8>::from_hex("1A2b"), Ok(vec![0x1a, 0x2b]));
+    /// ```
+    fn from_hex(bytes: impl AsRef<[u8]>) -> Result<Self, Self::Error>;
+
+    /// Try to parse `bytes`, only lowercase characters allowed.
+    ///
+    /// # Errors
+    /// - if `bytes` doesn't have a valid length for the target type
+    /// - if `bytes` contains characters other than `[0-9a-f]`
+    ///
+    /// # Example:
+    /// ```
+    /// use hex_str::FromHex;
+    ///
+    /// assert_eq!(Vec::<u8>::from_hex_lower("1a2b"), Ok(vec![0x1a, 0x2b]));
+    /// assert!(Vec::<u8>::from_hex_lower("1A2B").is_err());
+    /// ```
+    fn from_hex_lower(bytes: impl AsRef<[u8]>) -> Result<Self, Self::Error>;
+
+    /// Try to parse `bytes`, only uppercase characters allowed.
+    ///
+    /// # Errors
+    /// - if `bytes` doesn't have a valid length for the target type
+    /// - if `bytes` contains characters other than `[0-9A-F]`
+    ///
+    /// # Example:
+    /// ```
+    /// use hex_str::FromHex;
+    ///
+    /// assert_eq!(Vec::<u8>::from_hex_upper("1A2B"), Ok(vec![0x1a, 0x2b]));
+    /// assert!(Vec::<u8>::from_hex_upper("1a2b").is_err());
+    /// ```
+    fn from_hex_upper(bytes: impl AsRef<[u8]>) -> Result<Self, Self::Error>;
+}
+
+impl<const N: usize> FromHex for HexStringN<N> {
+    type Error = HexStringNError;
+
+    fn from_hex(bytes: impl AsRef<[u8]>) -> Result<Self, Self::Error> {
+        Self::try_parse(bytes)
+    }
+
+    fn from_hex_lower(bytes: impl AsRef<[u8]>) -> Result<Self, Self::Error> {
+        Self::try_parse_lower(bytes)
+    }
+
+    fn from_hex_upper(bytes: impl AsRef<[u8]>) -> Result<Self, Self::Error> {
+        Self::try_parse_upper(bytes)
+    }
+}
+
+impl<const N: usize> FromHex for [u8; N] {
+    type Error = HexStringNError;
+
+    fn from_hex(bytes: impl AsRef<[u8]>) -> Result<Self, Self::Error> {
+        HexStringN::try_parse(bytes).map(|v| *Box::from(v))
+    }
+
+    fn from_hex_lower(bytes: impl AsRef<[u8]>) -> Result<Self, Self::Error> {
+        HexStringN::try_parse_lower(bytes).map(|v| *Box::from(v))
+    }
+
+    fn from_hex_upper(bytes: impl AsRef<[u8]>) -> Result<Self, Self::Error> {
+        HexStringN::try_parse_upper(bytes).map(|v| *Box::from(v))
+    }
+}
+
+impl FromHex for HexString {
+    type Error = HexStringError;
+
+    fn from_hex(bytes: impl AsRef<[u8]>) -> Result<Self, Self::Error> {
+        Self::try_parse(bytes)
+    }
+
+    fn from_hex_lower(bytes: impl AsRef<[u8]>) -> Result<Self, Self::Error> {
+        Self::try_parse_lower(bytes)
+    }
+
+    fn from_hex_upper(bytes: impl AsRef<[u8]>) -> Result<Self, Self::Error> {
+        Self::try_parse_upper(bytes)
+    }
+}
+
+impl FromHex for Vec<u8> {
+    type Error = HexStringError;
+
+    fn from_hex(bytes: impl AsRef<[u8]>) -> Result<Self, Self::Error> {
+        HexString::try_parse(bytes).map(Vec::from)
+    }
+
+    fn from_hex_lower(bytes: impl AsRef<[u8]>) -> Result<Self, Self::Error> {
+        HexString::try_parse_lower(bytes).map(Vec::from)
+    }
+
+    fn from_hex_upper(bytes: impl AsRef<[u8]>) -> Result<Self, Self::Error> {
+        HexString::try_parse_upper(bytes).map(Vec::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{FromHex, ToHex};
+    use crate::{HexString, HexStringError, HexStringN, HexStringNError};
+
+    struct Custom([u8; 2]);
+
+    impl AsRef<[u8]> for Custom {
+        fn as_ref(&self) -> &[u8] {
+            &self.0
+        }
+    }
+
+    #[test]
+    fn to_hex() {
+        let bytes = [0x1a, 0x2b, 0xff, 0x00];
+
+        assert_eq!(bytes[..].to_hex_lower(), "1a2bff00");
+        assert_eq!(bytes.to_vec().to_hex_upper(), "1A2BFF00");
+        assert_eq!([0xab; 16].to_hex_lower(), "ab".repeat(16));
+        assert_eq!(Custom([0x1a, 0x2b]).to_hex_upper(), "1A2B");
+
+        let n = HexStringN::new(bytes);
+        assert_eq!(n.to_hex_lower(), n.to_lower());
+        assert_eq!(n.to_hex_upper(), n.to_upper());
+
+        let v = HexString::new(bytes);
+        assert_eq!(v.to_hex_lower(), v.to_lower());
+        assert_eq!(v.to_hex_upper(), v.to_upper());
+
+        let mut s = String::new();
+        bytes.write_hex_upper_to(&mut s).unwrap();
+        assert_eq!(s, v.to_upper());
+    }
+
+    #[test]
+    fn from_hex() {
+        assert_eq!(Vec::<u8>::from_hex("1a2B"), Ok(vec![0x1a, 0x2b]));
+        assert_eq!(<[u8; 2]>::from_hex("1a2B"), Ok([0x1a, 0x2b]));
+        assert_eq!(HexStringN::<2>::from_hex("1a2B").unwrap(), "1a2b");
+        assert_eq!(HexString::from_hex("1a2B").unwrap(), "1a2b");
+
+        assert_eq!(
+            Vec::<u8>::from_hex_lower("1a2B"),
+            Err(HexStringError::InvalidByte {
+                msb: b'2',
+                lsb: b'B',
+                index: 2
+            })
+        );
+        assert_eq!(
+            HexStringN::<2>::from_hex_upper("1a2B"),
+            Err(HexStringNError::InvalidByte {
+                msb: b'1',
+                lsb: b'a',
+                index: 0
+            })
+        );
+        assert_eq!(
+            HexStringN::<2>::from_hex("1a2b3c"),
+            Err(HexStringNError::InvalidLength {
+                expected: 4,
+                encountered: 6
+            })
+        );
+        assert_eq!(
+            <[u8; 2]>::from_hex_lower("1A2B"),
+            Err(HexStringNError::InvalidByte {
+                msb: b'1',
+                lsb: b'A',
+                index: 0
+            })
+        );
+        assert_eq!(
+            HexString::from_hex_lower("1a2").unwrap_err(),
+            HexStringError::InvalidLength { encountered: 3 }
+        );
+    }
+}
@@ -1,3 +1,5 @@
+use crate::LengthError;
+
 pub fn parse(a: u8, b: u8) -> Option<u8> {
     #[rustfmt::skip]
     static HEX_LSB: [i16; 256] = [
@@ -144,6 +146,19 @@ fn parse_(a: u8, b: u8, lut_lsb: &[i16; 256], lut_msb: &[i16; 256]) -> Option<u8
     }
 }
 
+pub const fn parse_nibble(v: u8) -> Option<u8> {
+    match v {
+        b'0'..=b'9' => Some(v - b'0'),
+        b'a'..=b'f' => Some(v - b'a' + 10),
+        b'A'..=b'F' => Some(v - b'A' + 10),
+        _ => None,
+    }
+}
+
+pub fn split_nibbles(v: u8) -> (u8, u8) {
+    ((v & 0xf0) >> 4, v & 0x0f)
+}
+
 pub fn to_hex_lower(v: u8) -> [u8; 2] {
     let helper = |v: u8| -> u8 {
         match v {
@@ -153,8 +168,7 @@ pub fn to_hex_lower(v: u8) -> [u8; 2] {
         }
     };
 
-    let a = (v & 0xf0) >> 4;
-    let b = v & 0x0f;
+    let (a, b) = split_nibbles(v);
 
     [helper(a), helper(b)]
 }
@@ -168,12 +182,109 @@ pub fn to_hex_upper(v: u8) -> [u8; 2] {
         }
     };
 
-    let a = (v & 0xf0) >> 4;
-    let b = v & 0x0f;
+    let (a, b) = split_nibbles(v);
 
     [helper(a), helper(b)]
 }
 
+pub fn encode_to_buf(
+    bytes: &[u8],
+    buf: &mut [u8],
+    conversion_fn: impl Fn(u8) -> [u8; 2],
+) -> Result<usize, LengthError> {
+    let required = bytes.len() * 2;
+    if buf.len() < required {
+        return Err(LengthError {
+            expected: required,
+            encountered: buf.len(),
+        });
+    }
+
+    for (v, dst) in bytes.iter().zip(buf.chunks_exact_mut(2)) {
+        dst.copy_from_slice(&conversion_fn(*v));
+    }
+
+    Ok(required)
+}
+
+pub fn write_to(
+    bytes: &[u8],
+    w: &mut impl core::fmt::Write,
+    conversion_fn: impl Fn(u8) -> [u8; 2],
+) -> core::fmt::Result {
+    for v in bytes {
+        let [a, b] = conversion_fn(*v);
+        w.write_char(a.into())?;
+        w.write_char(b.into())?;
+    }
+
+    Ok(())
+}
+
+/// Write `bytes` with `sep` inserted between every `group_size` bytes
+pub fn write_separated_to(
+    bytes: &[u8],
+    w: &mut impl core::fmt::Write,
+    sep: char,
+    group_size: usize,
+    conversion_fn: impl Fn(u8) -> [u8; 2],
+) -> core::fmt::Result {
+    assert!(group_size != 0, "group_size must be non-zero");
+
+    for (i, group) in bytes.chunks(group_size).enumerate() {
+        if i != 0 {
+            w.write_char(sep)?;
+        }
+        write_to(group, w, &conversion_fn)?;
+    }
+
+    Ok(())
+}
+
+pub enum SeparatedError {
+    Truncated,
+    InvalidByte { msb: u8, lsb: u8, index: usize },
+    UnexpectedSeparator { position: usize },
+    MissingSeparator { position: usize },
+}
+
+/// Parse pairs separated by `sep`, e.g. `01:23:45`
+#[cfg(feature = "alloc")]
+pub fn parse_separated(
+    bytes: &[u8],
+    sep: u8,
+    conversion_fn: impl Fn(u8, u8) -> Option<u8>,
+) -> Result<alloc::vec::Vec<u8>, SeparatedError> {
+    let mut ret = alloc::vec::Vec::with_capacity(bytes.len().div_ceil(3));
+    if bytes.is_empty() {
+        return Ok(ret);
+    }
+
+    let mut i = 0;
+    loop {
+        let [msb, lsb] = *bytes.get(i..i + 2).ok_or(SeparatedError::Truncated)? else {
+            unreachable!()
+        };
+
+        if let Some(j) = [msb, lsb].iter().position(|&v| v == sep) {
+            return Err(SeparatedError::UnexpectedSeparator { position: i + j });
+        }
+
+        ret.push(conversion_fn(msb, lsb).ok_or(SeparatedError::InvalidByte {
+            msb,
+            lsb,
+            index: i,
+        })?);
+
+        i += 2;
+        match bytes.get(i) {
+            None => return Ok(ret),
+            Some(&v) if v == sep => i += 1,
+            Some(_) => return Err(SeparatedError::MissingSeparator { position: i }),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     fn to_lower(v: u8) -> Option<u8> {
@@ -0,0 +1,151 @@
+//! Uses `hex_str`'s `no_std` API from a `#![no_std]` crate
+//!
+//! Built for `thumbv7m-none-eabi` in CI, where `std` doesn't exist, so any
+//! accidental dependency on it fails to build. The `alloc` feature adds the
+//! allocating types. The tests check the results on the host.
+
+#![cfg_attr(not(test), no_std)]
+#![warn(clippy::pedantic)]
+#![deny(rust_2018_idioms, unused, future_incompatible, nonstandard_style)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+use core::fmt::Write;
+
+use hex_str::{
+    decode_hex_array, encode_hex_lower_ascii_iter, encode_hex_lower_iter,
+    encode_hex_upper_ascii_iter, encode_hex_upper_iter, is_valid_hex_str, is_valid_lower_hex_str,
+    is_valid_upper_hex_str, HexByte, HexStringError, HexStringNError, LengthError,
+};
+
+const KEY: [u8; 4] = match decode_hex_array::<4>(b"deadBEEF") {
+    Ok(v) => v,
+    Err(_) => panic!("invalid hex literal"),
+};
+
+const _: () = assert!(matches!(
+    decode_hex_array::<2>(b"1a2"),
+    Err(HexStringNError::InvalidLength {
+        expected: 4,
+        encountered: 3
+    })
+));
+
+/// A fixed capacity `fmt::Write` target
+pub struct Buf {
+    bytes: [u8; 64],
+    len: usize,
+}
+
+impl Buf {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            bytes: [0; 64],
+            len: 0,
+        }
+    }
+
+    #[must_use]
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes[..self.len]
+    }
+}
+
+impl Default for Buf {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Write for Buf {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        let dst = self
+            .bytes
+            .get_mut(self.len..self.len + s.len())
+            .ok_or(core::fmt::Error)?;
+        dst.copy_from_slice(s.as_bytes());
+        self.len += s.len();
+
+        Ok(())
+    }
+}
+
+/// Encode and decode through every item that's available without `alloc`,
+/// return whether all of them agree
+#[must_use]
+pub fn round_trip() -> bool {
+    let mut lower = [0; 8];
+    for (dst, c) in lower.iter_mut().zip(encode_hex_lower_ascii_iter(KEY)) {
+        *dst = c;
+    }
+    let mut upper = [0; 8];
+    for (dst, c) in upper.iter_mut().zip(encode_hex_upper_ascii_iter(KEY)) {
+        *dst = c;
+    }
+
+    let mut buf = Buf::new();
+    let written = encode_hex_lower_iter(KEY)
+        .chain(encode_hex_upper_iter(KEY))
+        .try_for_each(|c| buf.write_char(c));
+
+    let mut bytes = Buf::new();
+    let displayed = KEY
+        .iter()
+        .try_for_each(|&v| write!(bytes, "{}", HexByte::new(v)));
+
+    written.is_ok()
+        && displayed.is_ok()
+        && buf.as_bytes() == b"deadbeefDEADBEEF"
+        && bytes.as_bytes() == lower
+        && is_valid_lower_hex_str(lower)
+        && is_valid_upper_hex_str(upper)
+        && !is_valid_hex_str("1g")
+        && decode_hex_array::<4>(&upper) == Ok(KEY)
+        && HexByte::try_parse(&lower[..2]) == Ok(HexByte(0xde))
+        && HexByte::from_pair(b'g', b'0').is_none()
+}
+
+/// Construct the error types, which don't need `alloc`
+#[must_use]
+pub fn errors() -> (HexStringError, LengthError) {
+    (
+        HexStringError::InvalidLength { encountered: 3 },
+        LengthError {
+            expected: 4,
+            encountered: 3,
+        },
+    )
+}
+
+/// Use the allocating types
+#[cfg(feature = "alloc")]
+#[must_use]
+pub fn allocating() -> bool {
+    use alloc::string::String;
+
+    use hex_str::{HexString, HexStringN, ToHex};
+
+    let v = HexStringN::new(KEY);
+    let mut s = String::new();
+
+    v.write_upper_to(&mut s).is_ok()
+        && s == "DEADBEEF"
+        && HexString::try_parse(&s).is_ok_and(|w| w == v)
+        && v.to_hex_lower() == "deadbeef"
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn round_trip() {
+        assert!(super::round_trip());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn allocating() {
+        assert!(super::allocating());
+    }
+}